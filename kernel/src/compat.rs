@@ -6,3 +6,16 @@ pub use std::collections::BTreeSet;
 pub use std::string::String;
 pub use std::vec;
 pub use std::vec::Vec;
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Pointer-width portability
+// ──────────────────────────────────────────────────────────────────────────────
+//
+// The kernel must produce bit-identical output on wasm32 (32-bit usize) and
+// 64-bit hosts. Consensus paths only ever WIDEN usize (to u64/u128), and only
+// NARROW it after an explicit bound check:
+// - `compute_merkle_root`: padding uses a checked power-of-two.
+// - `compute_bundle_hash`: be4/be2 length prefixes rely on `validate_limits`.
+// - `verify_quorum`: `signatures.len() as u64` is a widening cast.
+// Targets outside 32..=64-bit usize are unsupported; this fails the build.
+const _: () = assert!(usize::BITS >= 32 && usize::BITS <= 64);
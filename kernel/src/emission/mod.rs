@@ -1,6 +1,8 @@
 //! Emission module: the EmissionPolicy trait and ZeroEmission default.
 //! The emission model is intentionally decoupled from the physics engine.
 //! Physics compiles and passes determinism tests WITHOUT any emission logic.
-//! The SublinearBondEmission implementation is injected only after adversarial simulation.
+//! The SublinearBondEmission implementation is injected only after adversarial simulation;
+//! `sublinear` pins its formula and overflow policy ahead of that.
 pub mod policy;
+pub mod sublinear;
 pub mod zero;
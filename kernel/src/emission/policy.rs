@@ -12,6 +12,10 @@
 //!   3. isqrt of result
 //!   4. Fixed::from_raw(isqrt_result)
 //!   5. mul_scaled with Global_Entropy
+//!   6. mul_scaled with `duration_multiplier(Lock_Duration)` (1.0 by default)
+//!
+//! Step 1 overflowing u128 is `MathOverflow`, never a saturated mint
+//! (see `emission::sublinear` §Overflow Policy).
 
 use crate::math::fixed::Fixed;
 use crate::TransitionError;
@@ -35,4 +39,61 @@ pub trait EmissionPolicy {
     /// Calculate the validator fee from a completed epoch's total minted amount.
     /// Nominally a fraction (e.g. 10%) of total minted, redirected to the active committee.
     fn calculate_validator_fee(&self, total_epoch_minted: Fixed) -> Result<Fixed, TransitionError>;
+
+    /// Lock-duration bonus applied as a final `mul_scaled` factor on a bond
+    /// mint. Defaults to exactly 1.0 (no bonus), which leaves the mint
+    /// bit-identical. Override to reward longer locks, e.g. with a curve
+    /// capped at some maximum; policies that mint nothing may ignore it.
+    fn duration_multiplier(&self, _lock_duration_epochs: u64) -> Result<Fixed, TransitionError> {
+        Ok(Fixed::ONE)
+    }
+
+    /// Per-epoch inflation rate: `total_epoch_minted / total_supply` (floor).
+    /// Returns `DivisionByZero` if `total_supply` is zero.
+    fn inflation_rate(&self, total_epoch_minted: Fixed, total_supply: Fixed) -> Result<Fixed, TransitionError> {
+        total_epoch_minted.div_scaled(total_supply)
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mints nothing itself; only the provided `inflation_rate` is exercised.
+    struct Flat;
+
+    impl EmissionPolicy for Flat {
+        fn calculate_bond_mint(&self, _: Fixed, _: u64, _: Fixed) -> Result<Fixed, TransitionError> {
+            Ok(Fixed::zero())
+        }
+
+        fn calculate_validator_fee(&self, _: Fixed) -> Result<Fixed, TransitionError> {
+            Ok(Fixed::zero())
+        }
+    }
+
+    #[test]
+    fn default_duration_multiplier_is_one() {
+        for epochs in [0, 1, u64::MAX] {
+            assert_eq!(Flat.duration_multiplier(epochs), Ok(Fixed::ONE));
+        }
+    }
+
+    #[test]
+    fn inflation_rate_is_minted_over_supply() {
+        // 5 000 minted on 1 000 000 supply = 0.5%.
+        let rate = Flat.inflation_rate(Fixed::from_units(5_000).unwrap(), Fixed::from_units(1_000_000).unwrap());
+        assert_eq!(rate, Ok(Fixed::from_raw(5_000_000_000).unwrap()));
+    }
+
+    #[test]
+    fn inflation_rate_on_zero_supply_is_rejected() {
+        assert_eq!(
+            Flat.inflation_rate(Fixed::from_units(1).unwrap(), Fixed::zero()),
+            Err(TransitionError::DivisionByZero)
+        );
+    }
 }
@@ -0,0 +1,169 @@
+//! SublinearBondEmission: the constitutional formula from `policy.rs`.
+//!
+//! NOT WIRED INTO ANY TRANSITION. The kernel never calls an emission policy
+//! during Physics Layer validation; this type exists so the formula and its
+//! edge behavior are pinned before adversarial simulation selects it.
+//!
+//! ```text
+//! minted = isqrt[(Bond_Magnitude * Lock_Duration) / SCALE] * Global_Entropy
+//!          * duration_multiplier(Lock_Duration)
+//! ```
+//!
+//! The multiplier is the policy's `EmissionPolicy::duration_multiplier`, 1.0
+//! for `SublinearBondEmission`. Bonus curves reuse the formula through
+//! `sublinear_bond_mint` with their own override.
+//!
+//! # Overflow Policy (Frozen)
+//!
+//! Minting never saturates. The first step, `bond_magnitude.raw() *
+//! lock_duration_epochs`, is a checked u128 multiply; a product above
+//! `u128::MAX` returns `MathOverflow` and the host must reject the bond.
+//! A silent cap would mint the same amount for every over-large bond, making
+//! the excess lock free. Every later step is bounded once that product fits:
+//! `isqrt(u128::MAX / SCALE) < 2^64`, and multiplying by an entropy ≤ 1.0
+//! cannot overflow. The largest accepted input is therefore
+//! `bond_magnitude.raw() * lock_duration_epochs == u128::MAX`. A multiplier
+//! above 1.0 can overflow the final `mul_scaled`, also as `MathOverflow`.
+
+use crate::emission::policy::EmissionPolicy;
+use crate::math::fixed::{Fixed, SCALE};
+use crate::math::sqrt::isqrt;
+use crate::TransitionError;
+
+/// Share of an epoch's minted total paid to the active committee (10%).
+pub const VALIDATOR_FEE_BPS: u32 = 1_000;
+
+pub struct SublinearBondEmission;
+
+/// The constitutional bond mint, scaled by `policy.duration_multiplier`.
+///
+/// Returns `BondTooSmall` below 1.0 unit (MIN_BOND_MAGNITUDE) and
+/// `MathOverflow` if `bond_magnitude.raw() * lock_duration_epochs`
+/// exceeds `u128::MAX` (see module doc §Overflow Policy).
+pub fn sublinear_bond_mint<P: EmissionPolicy + ?Sized>(
+    policy: &P,
+    bond_magnitude: Fixed,
+    lock_duration_epochs: u64,
+    global_entropy: Fixed,
+) -> Result<Fixed, TransitionError> {
+    if bond_magnitude.raw() < SCALE {
+        return Err(TransitionError::BondTooSmall);
+    }
+    let product = bond_magnitude
+        .raw()
+        .checked_mul(lock_duration_epochs as u128)
+        .ok_or(TransitionError::MathOverflow)?;
+    let root = isqrt(product / SCALE);
+    Fixed::from_raw(root)?
+        .mul_scaled(global_entropy)?
+        .mul_scaled(policy.duration_multiplier(lock_duration_epochs)?)
+}
+
+impl EmissionPolicy for SublinearBondEmission {
+    /// `sublinear_bond_mint` with the default 1.0 duration multiplier.
+    fn calculate_bond_mint(
+        &self,
+        bond_magnitude: Fixed,
+        lock_duration_epochs: u64,
+        global_entropy: Fixed,
+    ) -> Result<Fixed, TransitionError> {
+        sublinear_bond_mint(self, bond_magnitude, lock_duration_epochs, global_entropy)
+    }
+
+    fn calculate_validator_fee(&self, total_epoch_minted: Fixed) -> Result<Fixed, TransitionError> {
+        total_epoch_minted.apply_bps(VALIDATOR_FEE_BPS)
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::fixed::MAX_SAFE_BALANCE_RAW;
+
+    /// Largest prime factor of u128::MAX = 2^128 - 1; fits a u64 duration.
+    const DURATION: u64 = 67_280_421_310_721;
+
+    /// +1% per epoch locked beyond 100, capped at 2.0 (reached at 200 epochs).
+    struct LockBonus;
+
+    impl EmissionPolicy for LockBonus {
+        fn calculate_bond_mint(&self, bond: Fixed, epochs: u64, entropy: Fixed) -> Result<Fixed, TransitionError> {
+            sublinear_bond_mint(self, bond, epochs, entropy)
+        }
+
+        fn calculate_validator_fee(&self, minted: Fixed) -> Result<Fixed, TransitionError> {
+            SublinearBondEmission.calculate_validator_fee(minted)
+        }
+
+        fn duration_multiplier(&self, epochs: u64) -> Result<Fixed, TransitionError> {
+            let bonus_bps = epochs.saturating_sub(100).min(100) * 100;
+            Fixed::ONE.checked_add(Fixed::ONE.apply_bps(bonus_bps as u32)?)
+        }
+    }
+
+    #[test]
+    fn mint_follows_the_constitutional_formula() {
+        // 100 units locked 100 epochs: isqrt(10^14 * 100 / 10^12) = 100 raw; × 0.5.
+        let half = Fixed::HALF;
+        let minted = SublinearBondEmission.calculate_bond_mint(Fixed::from_units(100).unwrap(), 100, half);
+        assert_eq!(minted, Ok(Fixed::from_raw(50).unwrap()));
+        assert_eq!(
+            SublinearBondEmission.calculate_validator_fee(Fixed::from_units(50).unwrap()),
+            Ok(Fixed::from_units(5).unwrap())
+        );
+        assert_eq!(
+            SublinearBondEmission.calculate_bond_mint(Fixed::from_raw(SCALE - 1).unwrap(), 1, Fixed::ONE),
+            Err(TransitionError::BondTooSmall)
+        );
+    }
+
+    #[test]
+    fn duration_bonus_scales_the_mint_up_to_its_cap() {
+        let bond = Fixed::from_units(10_000).unwrap();
+        // At or below 100 epochs the bonus is 1.0: identical to the plain formula.
+        for epochs in [1, 50, 100] {
+            assert_eq!(
+                LockBonus.calculate_bond_mint(bond, epochs, Fixed::ONE),
+                SublinearBondEmission.calculate_bond_mint(bond, epochs, Fixed::ONE)
+            );
+        }
+        // isqrt(10^16 * 150 / 10^12) = 1224 raw, × 1.5; at 400 epochs 2000 raw, × 2.0 (capped).
+        assert_eq!(LockBonus.calculate_bond_mint(bond, 150, Fixed::ONE).unwrap().raw(), 1_836);
+        assert_eq!(LockBonus.calculate_bond_mint(bond, 400, Fixed::ONE).unwrap().raw(), 4_000);
+        assert_eq!(LockBonus.duration_multiplier(u64::MAX), Fixed::from_units(2));
+    }
+
+    #[test]
+    fn product_exactly_at_u128_max_mints() {
+        let bond_raw = u128::MAX / DURATION as u128;
+        assert_eq!(bond_raw * DURATION as u128, u128::MAX, "DURATION divides 2^128 - 1");
+        assert!(bond_raw <= MAX_SAFE_BALANCE_RAW);
+
+        let minted = SublinearBondEmission
+            .calculate_bond_mint(Fixed::from_raw(bond_raw).unwrap(), DURATION, Fixed::ONE)
+            .unwrap();
+        assert_eq!(minted.raw(), isqrt(u128::MAX / SCALE));
+    }
+
+    #[test]
+    fn product_just_over_u128_max_is_rejected() {
+        let bond_raw = u128::MAX / DURATION as u128;
+        for (bond, duration) in [(bond_raw + 1, DURATION), (bond_raw, DURATION + 1)] {
+            assert_eq!(
+                SublinearBondEmission.calculate_bond_mint(Fixed::from_raw(bond).unwrap(), duration, Fixed::ONE),
+                Err(TransitionError::MathOverflow)
+            );
+        }
+        // The ceiling bond overflows at any duration above u128::MAX / MAX_SAFE_BALANCE_RAW.
+        let ceiling = Fixed::from_raw(MAX_SAFE_BALANCE_RAW).unwrap();
+        let max_duration = (u128::MAX / MAX_SAFE_BALANCE_RAW) as u64;
+        assert!(SublinearBondEmission.calculate_bond_mint(ceiling, max_duration, Fixed::ONE).is_ok());
+        assert_eq!(
+            SublinearBondEmission.calculate_bond_mint(ceiling, max_duration + 1, Fixed::ONE),
+            Err(TransitionError::MathOverflow)
+        );
+    }
+}
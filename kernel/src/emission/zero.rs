@@ -24,4 +24,24 @@ impl EmissionPolicy for ZeroEmission {
     ) -> Result<Fixed, TransitionError> {
         Ok(Fixed::zero())
     }
+
+    /// No tokens are created, so the rate is zero on any supply, including zero.
+    fn inflation_rate(&self, _total_epoch_minted: Fixed, _total_supply: Fixed) -> Result<Fixed, TransitionError> {
+        Ok(Fixed::zero())
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_emission_has_zero_inflation() {
+        let supply = Fixed::from_units(1_000_000).unwrap();
+        assert_eq!(ZeroEmission.inflation_rate(Fixed::zero(), supply), Ok(Fixed::zero()));
+        assert_eq!(ZeroEmission.inflation_rate(Fixed::zero(), Fixed::zero()), Ok(Fixed::zero()));
+    }
 }
@@ -5,5 +5,348 @@
 //! - VDF seed is recomputed from X-1 state exclusively. Fraudulent seed is severed.
 //! - FraudProofs are processed in ascending lexicographical order of their JCS hash.
 //! - Rewind across kernel_hash boundaries is forbidden.
+//!
+//! # What Counts as Fraud
+//!
+//! A `FraudProof` against epoch X carries the committed chain from X-1 to the
+//! current tip and a quorum-signed `StateWitnessBundle` for the transition
+//! X-1 → X. The chain is authenticated backwards from the tip
+//! (`verify_chain_backwards`), so any epoch inside the window can be disputed.
+//! Fraud is proven when re-executing that bundle yields a `state_root`
+//! different from the committed X: either the committee signed a wrong root, or it signed
+//! two different bundles for the same epoch. Both are rewound.
+//!
+//! NOT YET AN ENTRY POINT (v0.0.2): `verify_fraud_proof` and
+//! `process_fraud_proof(s)` are crate-private because a proof can be forged.
+//! The bundle sets its own `optimal_validator_count`, so one self-signed
+//! signature meets the quorum; signers are not checked against the committed
+//! `validator_set_root`; and nothing shows `witness` is the bundle the
+//! committee actually signed for `ancestry[1]`, since `EpochState` commits no
+//! bundle hash. They become public once the disputed epoch commits its bundle
+//! hash and proofs carry signer membership paths.
+//!
+//! # Rewind Result
+//!
+//! Rewinding returns the committed X-1 state itself. The orphaned epoch's
+//! `vdf_challenge_seed` is discarded with it; the replacement epoch X' is
+//! produced by a fresh `apply_epoch` from X-1, which derives its seed anew.
+
+use crate::physics::hashing::{ct_eq, sha256, Digest};
+use crate::state::epoch::{EpochState, MAX_FRAUD_WINDOW_EPOCHS};
+use crate::state::witness::StateWitnessBundle;
+use crate::transition::{verify_chain_backwards, verify_transition};
+use crate::TransitionError;
+
+/// A claim that the committed state of `epoch_number` is fraudulent.
+#[derive(Clone, Debug)]
+pub struct FraudProof {
+    /// The epoch whose committed state is disputed.
+    pub epoch_number: u64,
+    /// Committed states from `epoch_number - 1` (the rewind target) up to and
+    /// including the current tip, oldest first. `ancestry[1]` is the disputed
+    /// state; with `MAX_FRAUD_WINDOW_EPOCHS = 1` it is also the tip.
+    pub ancestry: Vec<EpochState>,
+    /// The quorum-signed bundle for the transition `ancestry[0] → epoch_number`.
+    pub witness: StateWitnessBundle,
+}
+
+impl FraudProof {
+    /// SHA-256 of the proof's JCS form — the batch processing order key.
+    ///
+    /// The JCS form is `{ancestry, epoch_number, witness}`: `ancestry` is an
+    /// array of `{state, state_root}` with each state's commitment object and
+    /// hex root, `witness` the bundle's `to_canonical_json` object.
+    /// Proofs too large for the canonical JSON limits return `InvalidSerialization`.
+    pub fn canonical_hash(&self) -> Result<Digest, TransitionError> {
+        use crate::physics::canonical_json::{parse, CanonicalObject, Value};
+        use crate::state::witness::hex;
+
+        let mut ancestry = Vec::with_capacity(self.ancestry.len());
+        for state in &self.ancestry {
+            ancestry.push(CanonicalObject::new()
+                .value("state", parse(&state.canonical_bytes()?)?)
+                .string("state_root", &hex(&state.state_root))
+                .into_value());
+        }
+        let json = CanonicalObject::new()
+            .value("ancestry", Value::Array(ancestry))
+            .string("epoch_number", self.epoch_number.to_string().as_bytes())
+            .value("witness", parse(&self.witness.to_canonical_json()?)?)
+            .finish()?;
+        Ok(sha256(&json))
+    }
+}
+
+/// Check that `chain[0]` is a legal rewind target for the disputed `epoch_number`.
+///
+/// `chain` is committed history, oldest first, ending at the current tip.
+/// - `epoch_number` is within `MAX_FRAUD_WINDOW_EPOCHS` of the tip → else `FraudWindowExpired`
+/// - `chain[0]` is the state of `epoch_number - 1`, and every state is
+///   committed and linked to the next (`verify_chain_backwards`) → else `InvalidSerialization`
+/// - every state shares the tip's `kernel_hash` → else `KernelHashMismatch`
+fn check_rewind_target(chain: &[EpochState], epoch_number: u64) -> Result<(), TransitionError> {
+    let [target, .., tip] = chain else {
+        return Err(TransitionError::InvalidSerialization);
+    };
+    // Age 0 = the latest committed epoch. Future epochs are outside the window too.
+    match tip.epoch_number.checked_sub(epoch_number) {
+        Some(age) if age < MAX_FRAUD_WINDOW_EPOCHS => {}
+        _ => return Err(TransitionError::FraudWindowExpired),
+    }
+
+    if target.epoch_number.checked_add(1) != Some(epoch_number) {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    verify_chain_backwards(tip, chain).map_err(|_| TransitionError::InvalidSerialization)?;
+
+    if chain.iter().any(|state| !ct_eq(&state.kernel_hash, &tip.kernel_hash)) {
+        return Err(TransitionError::KernelHashMismatch);
+    }
+    Ok(())
+}
+
+/// Verify `proof` against the latest committed state `current`.
+///
+/// Returns `Ok(())` only if fraud is proven: `proof.ancestry` ends at `current`,
+/// the rewind target is legal (see `check_rewind_target`), and re-executing
+/// `proof.witness` from `ancestry[0]` under `current.kernel_hash` produces a
+/// root other than the committed `ancestry[1]`. A matching root returns
+/// `FraudNotProven`; any error from re-execution (bad signatures, witnesses,
+/// limits) is returned unchanged.
+///
+/// Forgeable in v0.0.2 (see module doc), hence crate-private.
+#[cfg_attr(not(test), allow(dead_code))]
+pub(crate) fn verify_fraud_proof(proof: &FraudProof, current: &EpochState) -> Result<(), TransitionError> {
+    match proof.ancestry.last() {
+        Some(tip) if ct_eq(&tip.state_root, &current.state_root) => {}
+        _ => return Err(TransitionError::InvalidSerialization),
+    }
+    check_rewind_target(&proof.ancestry, proof.epoch_number)?;
+
+    let (prior, disputed) = (&proof.ancestry[0], &proof.ancestry[1]);
+    match verify_transition(prior, &proof.witness, current.kernel_hash, disputed) {
+        Err(TransitionError::StateRootMismatch) => Ok(()),
+        Ok(())                                  => Err(TransitionError::FraudNotProven),
+        Err(e)                                  => Err(e),
+    }
+}
+
+/// Rewind `chain` (committed history, oldest first, ending at the current
+/// tip) to the state before `epoch_number`, which must be `chain[0]`.
+///
+/// Performs the same target checks as `verify_fraud_proof` and returns
+/// `chain[0]` unchanged. Does NOT check for fraud: the caller must have
+/// established it (see the module doc for why the kernel cannot yet).
+pub fn rewind_to_previous(chain: &[EpochState], epoch_number: u64) -> Result<EpochState, TransitionError> {
+    check_rewind_target(chain, epoch_number)?;
+    Ok(chain[0].clone())
+}
+
+/// Top-level fraud handling: verify `proof`, then rewind.
+///
+/// On success returns the rewound state (`proof.ancestry[0]`). On an invalid,
+/// unproven or out-of-window proof returns the error; nothing is rewound.
+#[cfg_attr(not(test), allow(dead_code))]
+pub(crate) fn process_fraud_proof(current: &EpochState, proof: &FraudProof) -> Result<EpochState, TransitionError> {
+    verify_fraud_proof(proof, current)?;
+    rewind_to_previous(&proof.ancestry, proof.epoch_number)
+}
+
+/// Process a batch of proofs in ascending `canonical_hash` order.
+///
+/// The first proof that verifies triggers the rewind; the rest are ignored,
+/// since they dispute an epoch that is no longer canonical. Host order never
+/// matters. Returns `current` unchanged for an empty batch. If no proof
+/// verifies, returns the error of the first proof in hash order (or, if no
+/// proof could be hashed, the first hashing error).
+#[cfg_attr(not(test), allow(dead_code))]
+pub(crate) fn process_fraud_proofs(current: &EpochState, proofs: &[FraudProof]) -> Result<EpochState, TransitionError> {
+    let mut hash_err = None;
+    let mut ordered: Vec<(Digest, &FraudProof)> = Vec::with_capacity(proofs.len());
+    for proof in proofs {
+        match proof.canonical_hash() {
+            Ok(h)  => ordered.push((h, proof)),
+            Err(e) => { hash_err.get_or_insert(e); }
+        }
+    }
+    ordered.sort_by_key(|(h, _)| *h);
+
+    let mut first_err = None;
+    for (_, proof) in ordered {
+        match process_fraud_proof(current, proof) {
+            Ok(rewound) => return Ok(rewound),
+            Err(e)      => { first_err.get_or_insert(e); }
+        }
+    }
+    match first_err.or(hash_err) {
+        Some(e) => Err(e),
+        None    => Ok(current.clone()),
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests — use std only here
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::epoch::epoch_signing_message;
+    use crate::state::witness::{EntropyStats, ValidatorSignature};
+    use crate::transition::apply_epoch;
+
+    /// Empty bundle for `prior → prior + 1`, signed by a 2-of-3 quorum.
+    fn signed_bundle(prior: &EpochState, unique_active_validators: u64) -> StateWitnessBundle {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let mut witness = StateWitnessBundle {
+            bond_witnesses:      vec![],
+            entropy_stats:       EntropyStats {
+                active_bonded_magnitude_raw: 500_000_000_000,
+                total_supply_raw:            1_000_000_000_000,
+                unique_active_validators,
+                optimal_validator_count:     3,
+            },
+            impact_witnesses:    vec![],
+            mutation_authors:    vec![],
+            validator_signatures: vec![],
+            validator_witnesses: vec![],
+            vdf_proof:           None,
+        };
+        let message = epoch_signing_message(prior, &witness, &[0u8; 32]).unwrap();
+        let mut sigs: Vec<ValidatorSignature> = (1u8..=2).map(|seed| {
+            let key = SigningKey::from_bytes(&[seed; 32]);
+            ValidatorSignature {
+                validator_pubkey: key.verifying_key().to_bytes(),
+                signature:        key.sign(&message).to_bytes(),
+            }
+        }).collect();
+        sigs.sort_by_key(|s| s.validator_pubkey);
+        witness.validator_signatures = sigs;
+        witness
+    }
+
+    /// Genesis, the signed bundle, and a committed epoch 1 that claims the
+    /// wrong entropy (a fraudulent root over otherwise valid fields).
+    fn fraudulent_epoch() -> (EpochState, StateWitnessBundle, EpochState) {
+        let prior = EpochState::genesis().unwrap();
+        let witness = signed_bundle(&prior, 2);
+        let mut forged = apply_epoch(&prior, &witness, [0u8; 32]).unwrap();
+        forged.entropy_metric_scaled += 1;
+        let forged = forged.commit().unwrap();
+        (prior, witness, forged)
+    }
+
+    #[test]
+    fn valid_proof_rewinds_to_prior_state() {
+        let (prior, witness, current) = fraudulent_epoch();
+        let proof = FraudProof { epoch_number: 1, ancestry: vec![prior.clone(), current.clone()], witness };
+        assert_eq!(verify_fraud_proof(&proof, &current), Ok(()));
+        assert_eq!(process_fraud_proof(&current, &proof), Ok(prior));
+    }
+
+    #[test]
+    fn proof_against_honest_epoch_is_not_proven() {
+        let prior = EpochState::genesis().unwrap();
+        let witness = signed_bundle(&prior, 2);
+        let current = apply_epoch(&prior, &witness, [0u8; 32]).unwrap();
+        let before = current.clone();
+        let proof = FraudProof { epoch_number: 1, ancestry: vec![prior, current.clone()], witness };
+        assert_eq!(process_fraud_proof(&current, &proof), Err(TransitionError::FraudNotProven));
+        assert_eq!(current, before);
+    }
+
+    #[test]
+    fn unsigned_witness_is_rejected_without_rewind() {
+        let (prior, mut witness, current) = fraudulent_epoch();
+        witness.validator_signatures.clear();
+        let proof = FraudProof { epoch_number: 1, ancestry: vec![prior, current.clone()], witness };
+        assert_eq!(process_fraud_proof(&current, &proof), Err(TransitionError::InvalidSignature));
+    }
+
+    #[test]
+    fn proof_outside_window_is_rejected() {
+        let (prior, witness, current) = fraudulent_epoch();
+        let chain = vec![prior, current.clone()];
+        let stale = FraudProof { epoch_number: 0, ancestry: chain.clone(), witness: witness.clone() };
+        assert_eq!(process_fraud_proof(&current, &stale), Err(TransitionError::FraudWindowExpired));
+        let future = FraudProof { epoch_number: 2, ancestry: chain, witness };
+        assert_eq!(process_fraud_proof(&current, &future), Err(TransitionError::FraudWindowExpired));
+    }
+
+    #[test]
+    fn linked_ancestor_older_than_the_window_is_rejected() {
+        // A well-formed chain genesis → 1 → 2: epoch 1 is linked to the tip,
+        // but its age (1) is outside MAX_FRAUD_WINDOW_EPOCHS.
+        let (prior, witness, forged) = fraudulent_epoch();
+        let tip = apply_epoch(&forged, &signed_bundle(&forged, 2), [0u8; 32]).unwrap();
+        let proof = FraudProof { epoch_number: 1, ancestry: vec![prior, forged, tip.clone()], witness };
+        assert_eq!(process_fraud_proof(&tip, &proof), Err(TransitionError::FraudWindowExpired));
+    }
+
+    #[test]
+    fn ancestry_must_end_at_the_current_tip() {
+        let (prior, witness, current) = fraudulent_epoch();
+        let proof = FraudProof { epoch_number: 1, ancestry: vec![prior.clone()], witness: witness.clone() };
+        assert_eq!(process_fraud_proof(&current, &proof), Err(TransitionError::InvalidSerialization));
+        let proof = FraudProof { epoch_number: 1, ancestry: vec![prior.clone(), current.clone()], witness };
+        assert_eq!(process_fraud_proof(&prior, &proof), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn prior_not_linked_to_current_is_rejected() {
+        let (_, witness, current) = fraudulent_epoch();
+        let mut other = EpochState::genesis().unwrap();
+        other.validator_set_root = [0x11; 32];
+        let other = other.commit().unwrap();
+        let proof = FraudProof { epoch_number: 1, ancestry: vec![other, current.clone()], witness };
+        assert_eq!(process_fraud_proof(&current, &proof), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn rewind_across_kernel_hash_is_forbidden() {
+        let (prior, _, current) = fraudulent_epoch();
+        let mut upgraded = current.clone();
+        upgraded.kernel_hash = [0xAA; 32];
+        let upgraded = upgraded.commit().unwrap();
+        assert_eq!(rewind_to_previous(&[prior.clone(), upgraded], 1), Err(TransitionError::KernelHashMismatch));
+        assert_eq!(rewind_to_previous(&[prior.clone(), current], 1), Ok(prior));
+    }
+
+    #[test]
+    fn batch_order_is_independent_of_host_order() {
+        let (prior, witness, current) = fraudulent_epoch();
+        let chain = vec![prior.clone(), current.clone()];
+        let valid = FraudProof { epoch_number: 1, ancestry: chain.clone(), witness: witness.clone() };
+        let mut unsigned = witness.clone();
+        unsigned.validator_signatures.clear();
+        let invalid = FraudProof { epoch_number: 1, ancestry: chain, witness: unsigned };
+
+        let forward = process_fraud_proofs(&current, &[valid.clone(), invalid.clone()]);
+        let reverse = process_fraud_proofs(&current, &[invalid.clone(), valid]);
+        assert_eq!(forward, Ok(prior));
+        assert_eq!(forward, reverse);
+        assert_eq!(process_fraud_proofs(&current, &[invalid]), Err(TransitionError::InvalidSignature));
+        assert_eq!(process_fraud_proofs(&current, &[]), Ok(current));
+    }
+
+    #[test]
+    fn canonical_hash_commits_to_every_part() {
+        let (prior, witness, current) = fraudulent_epoch();
+        let proof = FraudProof { epoch_number: 1, ancestry: vec![prior, current], witness };
+        let base = proof.canonical_hash().unwrap();
+        assert_eq!(proof.canonical_hash().unwrap(), base);
 
-// Stub for v0.0.0-draft. Full implementation in v0.0.1-alpha.
+        let mut p = proof.clone();
+        p.epoch_number = 2;
+        assert_ne!(p.canonical_hash().unwrap(), base);
+        let mut p = proof.clone();
+        p.ancestry[0].state_root[0] ^= 1;
+        assert_ne!(p.canonical_hash().unwrap(), base);
+        let mut p = proof.clone();
+        p.ancestry.pop();
+        assert_ne!(p.canonical_hash().unwrap(), base);
+        let mut p = proof;
+        p.witness.entropy_stats.unique_active_validators = 3;
+        assert_ne!(p.canonical_hash().unwrap(), base);
+    }
+}
@@ -4,5 +4,72 @@
 //! - Uses saturating_sub_for_slash ONLY. Balance clamps to zero, never errors.
 //! - Max ONE slash per validator per epoch. Tracked via BTreeSet (not HashSet).
 //! - Slashed dust is burned (not redistributed). No incentive loops.
+//!
+//! # Penalty Schedule (Frozen)
+//!
+//! ```text
+//! InvalidStateTransition  10 000 bps  (100%)  signed a root that fraud proof refuted
+//! Equivocation             5 000 bps  ( 50%)  signed two conflicting transitions
+//! Downtime                   100 bps  (  1%)  missed the signature quorum
+//! ```
+//!
+//! The amount is `bonded.apply_bps(bps)`, floor-rounded; the caller subtracts
+//! it with `saturating_sub_for_slash`.
+
+use crate::math::fixed::Fixed;
+use crate::TransitionError;
+
+/// The offense a slash punishes. Each maps to one fixed basis-point penalty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SlashSeverity {
+    /// Missed participation: liveness, not safety.
+    Downtime,
+    /// Signed two conflicting transitions for the same epoch.
+    Equivocation,
+    /// Signed a transition that a fraud proof has refuted.
+    InvalidStateTransition,
+}
+
+impl SlashSeverity {
+    /// The frozen penalty in basis points of the bonded amount.
+    pub fn penalty_bps(self) -> u32 {
+        match self {
+            SlashSeverity::Downtime               => 100,
+            SlashSeverity::Equivocation           => 5_000,
+            SlashSeverity::InvalidStateTransition => 10_000,
+        }
+    }
+}
+
+/// The amount to slash from `bonded` for `severity`: `bonded * bps / 10_000`, floor.
+///
+/// Never exceeds `bonded`, so subtracting it cannot underflow.
+pub fn compute_slash_amount(bonded: Fixed, severity: SlashSeverity) -> Result<Fixed, TransitionError> {
+    bonded.apply_bps(severity.penalty_bps())
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_severity_takes_its_scheduled_share() {
+        let bonded = Fixed::from_units(1_000).unwrap();
+        assert_eq!(compute_slash_amount(bonded, SlashSeverity::Downtime), Ok(Fixed::from_units(10).unwrap()));
+        assert_eq!(compute_slash_amount(bonded, SlashSeverity::Equivocation), Ok(Fixed::from_units(500).unwrap()));
+        assert_eq!(compute_slash_amount(bonded, SlashSeverity::InvalidStateTransition), Ok(bonded));
+    }
 
-// Stub for v0.0.0-draft.
+    #[test]
+    fn penalties_grow_with_severity() {
+        let bonded = Fixed::from_raw(123_456_789_012_345).unwrap();
+        let amount = |s| compute_slash_amount(bonded, s).unwrap();
+        assert!(amount(SlashSeverity::Equivocation) > amount(SlashSeverity::Downtime));
+        assert!(amount(SlashSeverity::InvalidStateTransition) > amount(SlashSeverity::Equivocation));
+        assert!(amount(SlashSeverity::InvalidStateTransition) <= bonded);
+        assert_eq!(bonded.saturating_sub_for_slash(amount(SlashSeverity::InvalidStateTransition)), Fixed::zero());
+    }
+}
@@ -6,7 +6,8 @@
 //! for wasm32-unknown-unknown via `cargo build --target wasm32-unknown-unknown`.)
 //!
 //! INVARIANTS:
-//! 1. All arithmetic uses checked_* variants. Overflow → TransitionError::MathOverflow.
+//! 1. All arithmetic uses checked_* variants. Overflow → TransitionError::MathOverflow,
+//!    subtraction below zero → TransitionError::MathUnderflow.
 //! 2. All Fixed(u128) inner values are private. No raw .0 access in consensus paths.
 //! 3. BTreeMap used everywhere: iteration order is deterministic (sorted by key).
 //! 4. HashMap is forbidden in consensus code (random seed = non-determinism).
@@ -20,12 +21,16 @@ pub mod state;
 pub mod transition;
 pub mod fraud;
 pub mod emission;
+#[cfg(feature = "serde")]
+pub mod serde_tooling;
 
 /// The canonical error type for all state transition failures.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransitionError {
-    /// Checked arithmetic returned None: overflow or underflow.
+    /// Checked arithmetic exceeded its upper bound (u128 or MAX_SAFE_BALANCE_RAW).
     MathOverflow,
+    /// A subtraction would go below zero (taking more than is available).
+    MathUnderflow,
     /// Division by zero pre-check triggered.
     DivisionByZero,
     /// JSON payload violated canonical serialization rules (RFC 8785 / JCS).
@@ -46,4 +51,16 @@ pub enum TransitionError {
     FraudWindowExpired,
     /// Snapshot kernel hash diverges from current kernel.
     KernelHashMismatch,
+    /// A claimed post-transition state_root differs from the re-executed one.
+    StateRootMismatch,
+    /// A FraudProof re-executed to the committed state_root: no fraud shown.
+    FraudNotProven,
+    /// The previous epoch is already at the deployment's terminal epoch.
+    EpochLimitReached,
+    /// The host-declared signed-over `prev_state_root` is not the actual `prev`.
+    StaleBaseState,
+    /// A non-zero product floored to zero in a value-preserving multiply.
+    PrecisionUnderflow,
+    /// More than SHA256_MAX_INPUT_BYTES fed to SHA-256 (FIPS 180-4: < 2^64 bits).
+    HashInputTooLong,
 }
@@ -10,18 +10,68 @@
 //! - Every operation that can fail returns Result<Fixed, TransitionError>.
 //! - Division by zero pre-checks the denominator and returns DivisionByZero,
 //!   never a WASM trap.
+//!
+//! TWO STRING FORMS — do not confuse them:
+//! - Consensus: `from_canonical_str` / `to_canonical_str` carry the RAW scaled
+//!   value (`"1500000000000"` is 1.5 units). This is what gets committed.
+//! - Human: `from_decimal_str` / `to_decimal_str`, and the `FromStr` /
+//!   `Display` impls, carry decimal UNITS (`"1.5"`). Display and input only.
+//!
+//! LAWS (checked by the property suite in this file's tests):
+//! - Exact: `mul_scaled` and `checked_add` are commutative, including which
+//!   inputs overflow; `checked_add` then `checked_sub` restores the input;
+//!   `mul_scaled` by one is the identity; `from_canonical_str(to_canonical_str(x)) == x`.
+//! - Within truncation error: `a.div_scaled(b)?.mul_scaled(b)?` is ≤ `a` and
+//!   short of it by at most `ceil(b / SCALE)` ulps (1 ulp when `b` ≤ 1.0).
 
+use crate::math::overflow::checked_mul_div_raw;
 use crate::TransitionError;
 
 /// The scaling factor. 1.0 accountability unit = Fixed(1_000_000_000_000).
 pub const SCALE: u128 = 1_000_000_000_000;
 
+/// Basis points per whole: `apply_bps(10_000)` is the identity.
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
 /// Maximum safe raw value before a decay multiplication (balance * decay_factor)
 /// would overflow u128. Derived as: u128::MAX / SCALE.
 /// Any Fixed value whose inner u128 exceeds this should be considered a protocol
 /// invariant violation — individual balances must never reach this ceiling.
 pub const MAX_SAFE_BALANCE_RAW: u128 = u128::MAX / SCALE;
 
+/// How `mul_scaled_rounded` / `div_scaled_with` round the final division.
+///
+/// Consensus code uses `mul_scaled` / `div_scaled`, which are always `Floor`.
+/// The other modes exist for policy and analytics math; `HalfEven` (banker's
+/// rounding) keeps repeated operations free of systematic bias. A non-`Floor`
+/// mode is forbidden in any committed value until that call site and its
+/// mode are pinned by a constitutional vector: changing a mode is a fork.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Toward zero (the constitutional mode).
+    Floor,
+    /// Away from zero on any remainder.
+    Ceil,
+    /// To nearest; exact halves round up.
+    HalfUp,
+    /// To nearest; exact halves round to the even neighbour.
+    HalfEven,
+}
+
+/// `n / d` rounded by `mode`. `d` must be non-zero. Compares the remainder
+/// against `d - r` so no intermediate can overflow.
+fn div_rounded(n: u128, d: u128, mode: RoundingMode) -> u128 {
+    let (q, r) = (n / d, n % d);
+    let round_up = match mode {
+        RoundingMode::Floor    => false,
+        RoundingMode::Ceil     => r != 0,
+        RoundingMode::HalfUp   => r != 0 && r >= d - r,
+        RoundingMode::HalfEven => r > d - r || (r != 0 && r == d - r && q % 2 == 1),
+    };
+    // round_up implies r > 0, hence d ≥ 2 and q ≤ u128::MAX / 2: no overflow.
+    q + round_up as u128
+}
+
 /// The constitutional fixed-point type.
 /// The inner value is private — enforced by the Rust module system.
 /// Consensus code imports `Fixed` but cannot access `.0`.
@@ -29,6 +79,12 @@ pub const MAX_SAFE_BALANCE_RAW: u128 = u128::MAX / SCALE;
 pub struct Fixed(u128);
 
 impl Fixed {
+    /// 1.0 unit (raw = SCALE). Also the upper bound of a valid entropy.
+    pub const ONE: Fixed = Fixed(SCALE);
+
+    /// 0.5 unit (raw = SCALE / 2).
+    pub const HALF: Fixed = Fixed(SCALE / 2);
+
     /// Construct a Fixed from a pre-scaled raw u128.
     /// Returns an error if the raw value exceeds MAX_SAFE_BALANCE_RAW,
     /// as such values would overflow during a subsequent decay multiplication.
@@ -65,6 +121,70 @@ impl Fixed {
         Self::from_raw(raw)
     }
 
+    /// Serialize as a canonical numeric string — the exact inverse of
+    /// `from_canonical_str` (the raw inner value in decimal, no leading zeros).
+    pub fn to_canonical_str(self) -> String {
+        self.0.to_string()
+    }
+
+    /// Decompose into `(whole_units, fractional_raw)`: `(raw / SCALE, raw % SCALE)`.
+    /// NON-CONSENSUS helper for UIs and accounting exports; the fractional part
+    /// stays in raw terms (0 ≤ fractional_raw < SCALE).
+    pub fn split_units(self) -> (u128, u128) {
+        (self.0 / SCALE, self.0 % SCALE)
+    }
+
+    /// Parse the HUMAN decimal-units form: `"1.5"` is 1.5 units (raw 1.5 × SCALE).
+    ///
+    /// NOT the consensus form. `from_canonical_str("1500000000000")` reads a raw
+    /// scaled value; this reads units with a decimal point. Mixing them up is a
+    /// 10^12 error, so never use this for committed or signed data.
+    ///
+    /// Grammar: `^(0|[1-9][0-9]*)(\.[0-9]{1,12})?$`. Trailing fractional zeros
+    /// are accepted; more than 12 fractional digits would need rounding and
+    /// are rejected. Returns `InvalidSerialization` on a grammar violation and
+    /// `MathOverflow` above `MAX_SAFE_BALANCE_RAW`.
+    pub fn from_decimal_str(s: &str) -> Result<Self, TransitionError> {
+        let (whole, frac) = match s.split_once('.') {
+            Some((whole, frac)) => (whole, Some(frac)),
+            None                => (s, None),
+        };
+        let whole_valid = whole == "0" || (
+            !whole.is_empty()
+            && whole.as_bytes()[0] != b'0'
+            && whole.bytes().all(|b| b.is_ascii_digit())
+        );
+        let frac_valid = frac.is_none_or(|f| {
+            (1..=12).contains(&f.len()) && f.bytes().all(|b| b.is_ascii_digit())
+        });
+        if !whole_valid || !frac_valid {
+            return Err(TransitionError::InvalidSerialization);
+        }
+        let whole = whole.parse::<u128>().map_err(|_| TransitionError::MathOverflow)?;
+        // Right-pad the fraction to 12 digits: "5" → 500_000_000_000.
+        let frac_raw = frac.map_or(0, |f| {
+            f.bytes().chain(core::iter::repeat(b'0')).take(12).fold(0u128, |acc, b| acc * 10 + (b - b'0') as u128)
+        });
+        let raw = whole
+            .checked_mul(SCALE)
+            .and_then(|w| w.checked_add(frac_raw))
+            .ok_or(TransitionError::MathOverflow)?;
+        Self::from_raw(raw)
+    }
+
+    /// Render the HUMAN decimal-units form, the inverse of `from_decimal_str`:
+    /// whole units, then `.` and the fraction without trailing zeros (omitted
+    /// when zero). `Fixed(1.5 × SCALE)` → `"1.5"`. NOT the consensus form:
+    /// DISPLAY ONLY, never in a commitment, signature or canonical JSON field.
+    pub fn to_decimal_str(self) -> String {
+        let (whole, frac) = self.split_units();
+        if frac == 0 {
+            return whole.to_string();
+        }
+        let digits = format!("{:012}", frac);
+        format!("{}.{}", whole, digits.trim_end_matches('0'))
+    }
+
     /// Returns the inner raw u128 value.
     /// ONLY for use inside the `math` module and test harnesses.
     /// Consensus code outside this module cannot call this.
@@ -81,6 +201,23 @@ impl Fixed {
         Self::from_raw(result)
     }
 
+    /// `mul_scaled` with an explicit rounding mode. `Floor` is exactly `mul_scaled`.
+    pub fn mul_scaled_rounded(self, other: Fixed, mode: RoundingMode) -> Result<Fixed, TransitionError> {
+        let product = self.0.checked_mul(other.0).ok_or(TransitionError::MathOverflow)?;
+        Self::from_raw(div_rounded(product, SCALE, mode))
+    }
+
+    /// `mul_scaled`, but a non-zero product that floors to zero returns
+    /// `PrecisionUnderflow` instead of a silent `Fixed(0)`. For fee and dust
+    /// arithmetic that must not lose a whole value; `mul_scaled` keeps flooring.
+    pub fn mul_scaled_checked_nonzero(self, other: Fixed) -> Result<Fixed, TransitionError> {
+        let result = self.mul_scaled(other)?;
+        if result.0 == 0 && self.0 != 0 && other.0 != 0 {
+            return Err(TransitionError::PrecisionUnderflow);
+        }
+        Ok(result)
+    }
+
     /// Divide self by other, scaling correctly: (self.0 * SCALE) / other.0
     /// Pre-checks the denominator for zero before any division attempt.
     pub fn div_scaled(self, other: Fixed) -> Result<Fixed, TransitionError> {
@@ -92,16 +229,81 @@ impl Fixed {
         Self::from_raw(result)
     }
 
+    /// `div_scaled` with an explicit rounding mode. `Floor` is exactly `div_scaled`.
+    pub fn div_scaled_with(self, other: Fixed, mode: RoundingMode) -> Result<Fixed, TransitionError> {
+        if other.0 == 0 {
+            return Err(TransitionError::DivisionByZero);
+        }
+        let numerator = self.0.checked_mul(SCALE).ok_or(TransitionError::MathOverflow)?;
+        Self::from_raw(div_rounded(numerator, other.0, mode))
+    }
+
+    /// `div_scaled` rounded up: `ceil(self.0 * SCALE / other.0)`.
+    ///
+    /// For thresholds that must never round in the holder's favour, e.g. a
+    /// required bond: the result is ≥ the exact quotient, and one ulp above
+    /// `div_scaled` exactly when the division is not exact. Same
+    /// `DivisionByZero` / `MathOverflow` as `div_scaled`.
+    pub fn ceil_div_scaled(self, other: Fixed) -> Result<Fixed, TransitionError> {
+        self.div_scaled_with(other, RoundingMode::Ceil)
+    }
+
+    /// `self * num / den`, floor, through a 256-bit intermediate product.
+    ///
+    /// The ratio `num / den` is scale-free, so no SCALE factor is applied or
+    /// lost: `mul_scaled(num)?.div_scaled(den)` can overflow or truncate twice
+    /// where this cannot. Returns `DivisionByZero` if `den` is zero and
+    /// `MathOverflow` if the result exceeds MAX_SAFE_BALANCE_RAW.
+    pub fn mul_div(self, num: Fixed, den: Fixed) -> Result<Fixed, TransitionError> {
+        Self::from_raw(checked_mul_div_raw(self.0, num.0, den.0)?)
+    }
+
+    /// `self^exp`, defined as `exp` successive `mul_scaled` steps from 1.0, so
+    /// `decay_factor()?.checked_pow(12)` equals twelve `apply_decay` calls.
+    ///
+    /// NOT exponentiation by squaring: squaring truncates intermediate powers
+    /// in a different order (the decay factor drifts by an ulp from exp = 6),
+    /// so it is not bit-identical to the loop. Instead the loop stops as soon
+    /// as the value reaches a fixed point (zero, or a value a factor ≥ 1.0
+    /// no longer moves). Cost is O(exp) only while the value is still changing.
+    /// Returns `MathOverflow` once a step exceeds MAX_SAFE_BALANCE_RAW.
+    pub fn checked_pow(self, exp: u32) -> Result<Fixed, TransitionError> {
+        let mut acc = Self::ONE;
+        for _ in 0..exp {
+            let next = acc.mul_scaled(self)?;
+            if next == acc {
+                break;
+            }
+            acc = next;
+        }
+        Ok(acc)
+    }
+
+    /// `self * bps / 10_000`, floor. Returns `MathOverflow` if the result
+    /// exceeds MAX_SAFE_BALANCE_RAW (only possible for `bps > 10_000`).
+    pub fn apply_bps(self, bps: u32) -> Result<Fixed, TransitionError> {
+        let product = self.0.checked_mul(bps as u128).ok_or(TransitionError::MathOverflow)?;
+        Self::from_raw(product / BPS_DENOMINATOR)
+    }
+
     /// Add two Fixed values. Returns overflow error if result exceeds MAX_SAFE_BALANCE_RAW.
     pub fn checked_add(self, other: Fixed) -> Result<Fixed, TransitionError> {
         let sum = self.0.checked_add(other.0).ok_or(TransitionError::MathOverflow)?;
         Self::from_raw(sum)
     }
 
-    /// Subtract other from self. Returns overflow error if other > self.
+    /// Sum with `checked_add`, stopping at the first step above
+    /// MAX_SAFE_BALANCE_RAW with `MathOverflow`. Every partial sum is checked,
+    /// so the outcome does not depend on how a host chunks the aggregation.
+    /// The empty sum is zero.
+    pub fn checked_sum<I: IntoIterator<Item = Fixed>>(iter: I) -> Result<Fixed, TransitionError> {
+        iter.into_iter().try_fold(Fixed::zero(), Fixed::checked_add)
+    }
+
+    /// Subtract other from self. Returns `MathUnderflow` if other > self.
     /// For slashing (which must clamp to zero), use `saturating_sub_for_slash`.
     pub fn checked_sub(self, other: Fixed) -> Result<Fixed, TransitionError> {
-        let diff = self.0.checked_sub(other.0).ok_or(TransitionError::MathOverflow)?;
+        let diff = self.0.checked_sub(other.0).ok_or(TransitionError::MathUnderflow)?;
         Ok(Fixed(diff))
     }
 
@@ -118,10 +320,84 @@ impl Fixed {
         self.0 == 0
     }
 
+    /// Returns true if this Fixed value is exactly 1.0.
+    pub fn is_one(self) -> bool {
+        self == Self::ONE
+    }
+
+    /// Constant-time equality of the raw values: XOR, fold to one word, one
+    /// comparison, no early exit. Parallels `physics::hashing::ct_eq`.
+    ///
+    /// `==` is fine for public values (committed balances, roots' inputs).
+    /// Use this where the timing of the comparison could reveal a secret:
+    /// checking a secret-dependent amount against a threshold or a claimed
+    /// value, e.g. whether a sealed bid or an unrevealed stake hit an exact target.
+    pub fn ct_eq(self, other: Fixed) -> bool {
+        let diff = self.0 ^ other.0;
+        ((diff as u64) | ((diff >> 64) as u64)) == 0
+    }
+
     /// Returns the zero Fixed value.
     pub fn zero() -> Fixed {
         Fixed(0)
     }
+
+    /// Arithmetic mean of two values, truncated (floor). Cannot overflow:
+    /// computed as `a/2 + b/2 + (1 if both are odd)` on the raw values.
+    pub fn average(self, other: Fixed) -> Fixed {
+        Fixed(self.0 / 2 + other.0 / 2 + (self.0 & other.0 & 1))
+    }
+}
+
+/// Human decimal units via `to_decimal_str` — `format!("{}", x)` of 1.5 units
+/// is `"1.5"`. For consensus bytes use `to_canonical_str` (raw scaled value).
+impl core::fmt::Display for Fixed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_decimal_str())
+    }
+}
+
+/// Human decimal units via `from_decimal_str` — `"1.5".parse()` is 1.5 units.
+/// For consensus strings use `from_canonical_str` (raw scaled value).
+impl core::str::FromStr for Fixed {
+    type Err = TransitionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_decimal_str(s)
+    }
+}
+
+/// Median of `values`: the middle element for an odd count, or the `average`
+/// of the two middle elements for an even count.
+///
+/// NON-CONSENSUS statistics helper for hosts. Returns `DivisionByZero` for an
+/// empty slice (the median of nothing is undefined).
+pub fn median(values: &[Fixed]) -> Result<Fixed, TransitionError> {
+    if values.is_empty() {
+        return Err(TransitionError::DivisionByZero);
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort();
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        Ok(sorted[mid])
+    } else {
+        Ok(sorted[mid - 1].average(sorted[mid]))
+    }
+}
+
+/// Exact ratio comparison: `a / b >= c / d`, by cross-multiplying raw values.
+///
+/// No division is performed, so truncation cannot flip a threshold decision.
+/// Returns `DivisionByZero` if either denominator is zero and `MathOverflow`
+/// if a cross product exceeds u128.
+pub fn ratio_at_least(a: Fixed, b: Fixed, c: Fixed, d: Fixed) -> Result<bool, TransitionError> {
+    if b.is_zero() || d.is_zero() {
+        return Err(TransitionError::DivisionByZero);
+    }
+    let lhs = a.0.checked_mul(d.0).ok_or(TransitionError::MathOverflow)?;
+    let rhs = c.0.checked_mul(b.0).ok_or(TransitionError::MathOverflow)?;
+    Ok(lhs >= rhs)
 }
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -145,6 +421,65 @@ mod tests {
         assert_eq!(a.mul_scaled(b).unwrap().raw(), 6 * SCALE);
     }
 
+    #[test]
+    fn sub_scale_product_floors_plain_and_errors_checked() {
+        // raw 1000 * raw 1000 = 10^6 < SCALE: floors to zero.
+        let dust = Fixed::from_raw(1_000).unwrap();
+        assert_eq!(dust.mul_scaled(dust), Ok(Fixed::zero()));
+        assert_eq!(dust.mul_scaled_checked_nonzero(dust), Err(TransitionError::PrecisionUnderflow));
+
+        // A genuinely zero operand, or a product that survives, is not an error.
+        assert_eq!(dust.mul_scaled_checked_nonzero(Fixed::zero()), Ok(Fixed::zero()));
+        let half = Fixed::from_raw(SCALE / 2).unwrap();
+        assert_eq!(dust.mul_scaled_checked_nonzero(half), Ok(Fixed::from_raw(500).unwrap()));
+    }
+
+    #[test]
+    fn rounding_modes_differ_on_halves_and_floor_matches() {
+        use RoundingMode::*;
+        let ulps = |raw: u128| Fixed::from_raw(raw).unwrap();
+        let half = ulps(SCALE / 2);
+        // 5 ulp * 0.5 = 2.5 ulp; 7 ulp * 0.5 = 3.5 ulp; 5 ulp / 2.0 = 2.5 ulp.
+        let two = Fixed::from_units(2).unwrap();
+        for (mode, at_2_5, at_3_5) in [(Floor, 2, 3), (Ceil, 3, 4), (HalfUp, 3, 4), (HalfEven, 2, 4)] {
+            assert_eq!(ulps(5).mul_scaled_rounded(half, mode), Ok(ulps(at_2_5)), "{:?}", mode);
+            assert_eq!(ulps(7).mul_scaled_rounded(half, mode), Ok(ulps(at_3_5)), "{:?}", mode);
+            assert_eq!(ulps(5).div_scaled_with(two, mode), Ok(ulps(at_2_5)), "{:?}", mode);
+        }
+        // Off-half remainders: only Ceil moves 2.4 up; HalfUp and HalfEven move 2.6 up.
+        let point_four = ulps(4 * SCALE / 10);
+        assert_eq!(ulps(6).mul_scaled_rounded(point_four, HalfEven), Ok(ulps(2)));
+        assert_eq!(ulps(6).mul_scaled_rounded(point_four, Ceil), Ok(ulps(3)));
+        let point_six_five = ulps(65 * SCALE / 100);
+        assert_eq!(ulps(4).mul_scaled_rounded(point_six_five, HalfEven), Ok(ulps(3)));
+        assert_eq!(ulps(4).mul_scaled_rounded(point_six_five, HalfUp), Ok(ulps(3)));
+
+        // Floor is bit-identical to the constitutional methods.
+        for (a, b) in [(7, SCALE / 3), (SCALE * 5, SCALE * 3), (123_456_789, 987_654_321_000)] {
+            let (a, b) = (ulps(a), ulps(b));
+            assert_eq!(a.mul_scaled_rounded(b, Floor), a.mul_scaled(b));
+            assert_eq!(a.div_scaled_with(b, Floor), a.div_scaled(b));
+        }
+        assert_eq!(two.div_scaled_with(Fixed::zero(), HalfEven), Err(TransitionError::DivisionByZero));
+
+        // At the u128 ceiling: a = 67_280_421_310_721 divides u128::MAX exactly, so
+        // a * b is the full product and floors to MAX_SAFE_BALANCE_RAW with a remainder.
+        let a = 67_280_421_310_721u128;
+        let (a, b) = (ulps(a), ulps(u128::MAX / a));
+        assert_eq!(a.mul_scaled_rounded(b, Floor), Ok(ulps(MAX_SAFE_BALANCE_RAW)));
+        assert_eq!(a.mul_scaled_rounded(b, Ceil), Err(TransitionError::MathOverflow));
+    }
+
+    #[test]
+    fn apply_bps_takes_a_floored_share() {
+        let hundred = Fixed::from_units(100).unwrap();
+        assert_eq!(hundred.apply_bps(10_000), Ok(hundred));
+        assert_eq!(hundred.apply_bps(250), Ok(Fixed::from_raw(2_500_000_000_000).unwrap()));
+        assert_eq!(hundred.apply_bps(0), Ok(Fixed::zero()));
+        // 1 bp of 9 ulps floors to zero.
+        assert_eq!(Fixed::from_raw(9).unwrap().apply_bps(1), Ok(Fixed::zero()));
+    }
+
     #[test]
     fn div_by_zero_returns_error() {
         let a = Fixed::from_units(1).unwrap();
@@ -159,6 +494,86 @@ mod tests {
         assert_eq!(balance.saturating_sub_for_slash(huge_slash), Fixed::zero());
     }
 
+    #[test]
+    fn checked_sub_below_zero_is_underflow() {
+        let a = Fixed::from_units(1).unwrap();
+        let b = Fixed::from_units(2).unwrap();
+        assert_eq!(a.checked_sub(b), Err(TransitionError::MathUnderflow));
+        assert_eq!(b.checked_sub(a).unwrap(), a);
+    }
+
+    #[test]
+    fn checked_add_past_max_is_overflow() {
+        let max = Fixed::from_raw(MAX_SAFE_BALANCE_RAW).unwrap();
+        assert_eq!(max.checked_add(Fixed::from_raw(1).unwrap()), Err(TransitionError::MathOverflow));
+    }
+
+    #[test]
+    fn split_units_separates_whole_and_fraction() {
+        use crate::state::decay::DECAY_FACTOR_SCALED;
+        assert_eq!(Fixed::from_units(5).unwrap().split_units(), (5, 0));
+        assert_eq!(Fixed::from_raw(DECAY_FACTOR_SCALED).unwrap().split_units(), (0, 943_932_824_245));
+        assert_eq!(Fixed(7 * SCALE + 1).split_units(), (7, 1));
+        let (whole, frac) = Fixed(MAX_SAFE_BALANCE_RAW).split_units();
+        assert_eq!(whole * SCALE + frac, MAX_SAFE_BALANCE_RAW);
+    }
+
+    #[test]
+    fn average_is_floor_and_overflow_free() {
+        let max = Fixed(MAX_SAFE_BALANCE_RAW);
+        assert_eq!(max.average(max), max);
+        assert_eq!(Fixed(3).average(Fixed(4)), Fixed(3));
+        assert_eq!(Fixed(3).average(Fixed(5)), Fixed(4));
+        assert_eq!(Fixed(u128::MAX).average(Fixed(u128::MAX)), Fixed(u128::MAX));
+    }
+
+    #[test]
+    fn ratio_at_least_is_exact_where_division_truncates() {
+        let f = |raw| Fixed::from_raw(raw).unwrap();
+        let one = Fixed::from_units(1).unwrap();
+
+        // Equal: 2/4 vs 1/2.
+        assert_eq!(ratio_at_least(f(2), f(4), f(1), f(2)), Ok(true));
+        assert_eq!(ratio_at_least(f(1), f(2), f(2), f(4)), Ok(true));
+
+        // 1/3 vs 0.333333333333: division truncates 1/3 to exactly the threshold.
+        let third = f(1).div_scaled(f(3)).unwrap();
+        let threshold = f(333_333_333_333);
+        assert_eq!(third, threshold.div_scaled(one).unwrap());
+        // Just above: 1/3 > 0.333333333333 (the naive comparison says equal).
+        assert_eq!(ratio_at_least(f(1), f(3), threshold, one), Ok(true));
+        // Just below: 0.333333333333 < 1/3 (the naive comparison wrongly accepts).
+        assert!(threshold.div_scaled(one).unwrap() >= third);
+        assert_eq!(ratio_at_least(threshold, one, f(1), f(3)), Ok(false));
+    }
+
+    #[test]
+    fn ratio_at_least_rejects_zero_denominators_and_overflow() {
+        let one = Fixed::from_units(1).unwrap();
+        assert_eq!(ratio_at_least(one, Fixed::zero(), one, one), Err(TransitionError::DivisionByZero));
+        assert_eq!(ratio_at_least(one, one, one, Fixed::zero()), Err(TransitionError::DivisionByZero));
+        let big = Fixed::from_raw(MAX_SAFE_BALANCE_RAW).unwrap();
+        assert_eq!(ratio_at_least(big, one, one, big), Err(TransitionError::MathOverflow));
+    }
+
+    #[test]
+    fn median_odd_count_is_middle_element() {
+        let v = [Fixed(9), Fixed(1), Fixed(5)];
+        assert_eq!(median(&v), Ok(Fixed(5)));
+    }
+
+    #[test]
+    fn median_even_count_averages_middle_pair() {
+        let v = [Fixed(10), Fixed(1), Fixed(4), Fixed(1_000)];
+        assert_eq!(median(&v), Ok(Fixed(7)));
+    }
+
+    #[test]
+    fn median_single_and_empty() {
+        assert_eq!(median(&[Fixed(42)]), Ok(Fixed(42)));
+        assert_eq!(median(&[]), Err(TransitionError::DivisionByZero));
+    }
+
     #[test]
     fn from_canonical_str_valid() {
         assert!(Fixed::from_canonical_str("0").is_ok());
@@ -180,4 +595,271 @@ mod tests {
             Err(TransitionError::InvalidSerialization)
         );
     }
+
+    // ── Property laws ─────────────────────────────────────────────────────────
+
+    /// Deterministic 64-bit LCG (Knuth MMIX constants). No external deps.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            self.0
+        }
+
+        /// A raw value ≤ MAX_SAFE_BALANCE_RAW with a random bit width, so small,
+        /// unit-scale and near-ceiling magnitudes are all exercised.
+        fn fixed(&mut self) -> Fixed {
+            let wide = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+            let bits = (self.next_u64() % 89) as u32; // MAX_SAFE_BALANCE_RAW < 2^89
+            let raw = if bits == 0 { 0 } else { wide >> (128 - bits) };
+            Fixed(raw.min(MAX_SAFE_BALANCE_RAW))
+        }
+    }
+
+    const CASES: usize = 5_000;
+
+    #[test]
+    fn law_mul_scaled_is_commutative() {
+        let mut rng = Lcg(1);
+        for _ in 0..CASES {
+            let (a, b) = (rng.fixed(), rng.fixed());
+            assert_eq!(a.mul_scaled(b), b.mul_scaled(a), "a={:?} b={:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn law_checked_add_is_commutative_and_sub_inverts_it() {
+        let mut rng = Lcg(2);
+        for _ in 0..CASES {
+            let (a, b) = (rng.fixed(), rng.fixed());
+            let sum = a.checked_add(b);
+            assert_eq!(sum, b.checked_add(a), "a={:?} b={:?}", a, b);
+            if let Ok(s) = sum {
+                assert_eq!(s.checked_sub(b), Ok(a));
+                assert_eq!(s.checked_sub(a), Ok(b));
+            }
+        }
+    }
+
+    #[test]
+    fn law_mul_by_one_is_identity() {
+        let mut rng = Lcg(3);
+        let one = Fixed::from_units(1).unwrap();
+        for _ in 0..CASES {
+            let a = rng.fixed();
+            match a.mul_scaled(one) {
+                Ok(r)  => assert_eq!(r, a),
+                // Only the pre-division product can overflow: raw * SCALE > u128::MAX.
+                Err(e) => {
+                    assert_eq!(e, TransitionError::MathOverflow);
+                    assert!(a.raw().checked_mul(SCALE).is_none());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn law_canonical_str_round_trips() {
+        let mut rng = Lcg(4);
+        for _ in 0..CASES {
+            let a = rng.fixed();
+            assert_eq!(Fixed::from_canonical_str(&a.to_canonical_str()), Ok(a));
+            let units = rng.next_u64() as u128 % (MAX_SAFE_BALANCE_RAW / SCALE + 1);
+            let f = Fixed::from_units(units).unwrap();
+            assert_eq!(f.to_canonical_str(), (units * SCALE).to_string());
+            assert_eq!(Fixed::from_canonical_str(&f.to_canonical_str()), Ok(f));
+        }
+    }
+
+    #[test]
+    fn canonical_str_round_trips_at_the_edges() {
+        let cases = [
+            (0, "0"),
+            (SCALE, "1000000000000"),
+            (MAX_SAFE_BALANCE_RAW, "340282366920938463463374607"),
+        ];
+        for (raw, text) in cases {
+            let f = Fixed::from_raw(raw).unwrap();
+            assert_eq!(f.to_canonical_str(), text);
+            assert_eq!(Fixed::from_canonical_str(text), Ok(f));
+        }
+    }
+
+    #[test]
+    fn law_div_then_mul_inverts_within_truncation() {
+        let mut rng = Lcg(5);
+        let mut checked = 0;
+        for _ in 0..CASES {
+            let (a, b) = (rng.fixed(), rng.fixed());
+            let Ok(q) = a.div_scaled(b) else { continue };
+            let Ok(r) = q.mul_scaled(b) else { continue };
+            checked += 1;
+            assert!(r <= a, "a={:?} b={:?} r={:?}", a, b, r);
+            assert!(a.raw() - r.raw() <= b.raw().div_ceil(SCALE), "a={:?} b={:?} r={:?}", a, b, r);
+        }
+        assert!(checked > CASES / 10, "too few non-overflowing cases: {}", checked);
+    }
+
+    #[test]
+    fn checked_sum_stops_at_the_first_overflow() {
+        let units = |n: u128| Fixed::from_units(n).unwrap();
+        assert_eq!(Fixed::checked_sum([]), Ok(Fixed::zero()));
+        assert_eq!(Fixed::checked_sum((1..=10).map(units)), Ok(units(55)));
+
+        // Overflows at the third element; the fourth is never pulled.
+        let ceiling = Fixed::from_raw(MAX_SAFE_BALANCE_RAW).unwrap();
+        let items = [Fixed::HALF, Fixed::HALF, ceiling, Fixed::ONE];
+        let mut pulled = 0;
+        let sum = Fixed::checked_sum(items.iter().copied().inspect(|_| pulled += 1));
+        assert_eq!(sum, Err(TransitionError::MathOverflow));
+        assert_eq!(pulled, 3);
+
+        // Exactly at the ceiling is fine.
+        assert_eq!(Fixed::checked_sum([ceiling.checked_sub(Fixed::ONE).unwrap(), Fixed::ONE]), Ok(ceiling));
+    }
+
+    #[test]
+    fn one_and_half_track_scale() {
+        assert_eq!(Ok(Fixed::ONE), Fixed::from_units(1));
+        assert_eq!(Ok(Fixed::HALF), Fixed::from_raw(SCALE / 2));
+        assert_eq!(Fixed::HALF.checked_add(Fixed::HALF), Ok(Fixed::ONE));
+        assert!(Fixed::ONE.is_one());
+        assert!(!Fixed::HALF.is_one() && !Fixed::from_raw(SCALE + 1).unwrap().is_one());
+        let x = Fixed::from_raw(943_932_824_245).unwrap();
+        assert_eq!(x.mul_scaled(Fixed::ONE), Ok(x));
+    }
+
+    #[test]
+    fn ceil_div_is_one_ulp_above_floor_only_when_inexact() {
+        let units = |n: u128| Fixed::from_units(n).unwrap();
+        // 10 / 3 = 3.333…: floor and ceiling differ by exactly one raw unit.
+        let (floor, ceil) = (units(10).div_scaled(units(3)).unwrap(), units(10).ceil_div_scaled(units(3)).unwrap());
+        assert_eq!(floor, Fixed::from_raw(3_333_333_333_333).unwrap());
+        assert_eq!(ceil.raw(), floor.raw() + 1);
+        // Exact quotients agree.
+        for (a, b) in [(10, 4), (9, 3), (0, 7)] {
+            assert_eq!(units(a).ceil_div_scaled(units(b)), units(a).div_scaled(units(b)));
+        }
+        assert_eq!(units(1).ceil_div_scaled(Fixed::zero()), Err(TransitionError::DivisionByZero));
+    }
+
+    #[test]
+    fn mul_div_survives_where_the_chained_path_overflows() {
+        let ceiling = Fixed::from_raw(MAX_SAFE_BALANCE_RAW).unwrap();
+        let (three, four) = (Fixed::from_units(3).unwrap(), Fixed::from_units(4).unwrap());
+        assert_eq!(ceiling.mul_scaled(three), Err(TransitionError::MathOverflow));
+        assert_eq!(ceiling.mul_div(three, four), Fixed::from_raw(MAX_SAFE_BALANCE_RAW * 3 / 4));
+
+        // A 1/3 fee share of 10 units: one floor, no double truncation.
+        let ten = Fixed::from_units(10).unwrap();
+        let (one, third) = (Fixed::from_units(1).unwrap(), Fixed::from_units(3).unwrap());
+        assert_eq!(ten.mul_div(one, third), Fixed::from_raw(3_333_333_333_333));
+        assert_eq!(ten.mul_div(three, three), Ok(ten));
+
+        assert_eq!(ten.mul_div(one, Fixed::zero()), Err(TransitionError::DivisionByZero));
+        assert_eq!(ceiling.mul_div(four, three), Err(TransitionError::MathOverflow));
+    }
+
+    #[test]
+    fn checked_pow_matches_iterated_mul_scaled() {
+        use crate::state::decay::{apply_decay, decay_factor};
+
+        let factors = [
+            decay_factor().unwrap(),
+            Fixed::from_raw(SCALE / 2).unwrap(),
+            Fixed::from_units(1).unwrap(),
+            Fixed::from_raw(SCALE + 1).unwrap(),
+            Fixed::from_units(3).unwrap(),
+            Fixed::zero(),
+        ];
+        for f in factors {
+            let mut looped = Fixed::from_units(1).unwrap();
+            for n in 0..=40 {
+                assert_eq!(f.checked_pow(n), Ok(looped), "f={:?} n={}", f, n);
+                looped = looped.mul_scaled(f).unwrap();
+                if f == Fixed::from_units(3).unwrap() && n >= 24 {
+                    break;
+                }
+            }
+        }
+
+        // Twelve epochs of decay compound to the same value as checked_pow(12).
+        let mut balance = Fixed::from_units(1).unwrap();
+        for _ in 0..12 {
+            balance = apply_decay(balance).unwrap();
+        }
+        assert_eq!(decay_factor().unwrap().checked_pow(12), Ok(balance));
+
+        // Squaring would differ: (f^2)^3 is one ulp off f^6.
+        let f = decay_factor().unwrap();
+        let squared = f.mul_scaled(f).unwrap();
+        let by_squaring = squared.mul_scaled(squared).unwrap().mul_scaled(squared).unwrap();
+        assert_ne!(f.checked_pow(6), Ok(by_squaring));
+
+        assert_eq!(Fixed::from_units(2).unwrap().checked_pow(100), Err(TransitionError::MathOverflow));
+        // A factor below 1.0 reaches zero and stops, however large the exponent.
+        assert_eq!(f.checked_pow(u32::MAX), Ok(Fixed::zero()));
+    }
+
+    #[test]
+    fn ct_eq_agrees_with_eq() {
+        let cases = [0, 1, SCALE, u64::MAX as u128, 1u128 << 64, (1u128 << 64) | 1, MAX_SAFE_BALANCE_RAW];
+        for &a in &cases {
+            for &b in &cases {
+                let (fa, fb) = (Fixed::from_raw(a).unwrap(), Fixed::from_raw(b).unwrap());
+                assert_eq!(fa.ct_eq(fb), fa == fb, "a={} b={}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn display_renders_decimal_units() {
+        assert_eq!(format!("{}", Fixed::from_raw(1_500_000_000_000).unwrap()), "1.5");
+        assert_eq!(format!("{}", Fixed::from_units(42).unwrap()), "42");
+        assert_eq!(format!("{}", Fixed::zero()), "0");
+        assert_eq!(format!("{}", Fixed::from_raw(1).unwrap()), "0.000000000001");
+        // The consensus form of the same value is the raw integer.
+        assert_eq!(Fixed::from_raw(1_500_000_000_000).unwrap().to_canonical_str(), "1500000000000");
+    }
+
+    #[test]
+    fn decimal_units_render_whole_dust_and_decay() {
+        use crate::state::decay::DECAY_FACTOR_SCALED;
+
+        assert_eq!(Fixed::from_units(1).unwrap().to_decimal_str(), "1");
+        assert_eq!(Fixed::from_units(1_000).unwrap().to_decimal_str(), "1000");
+        assert_eq!(Fixed::from_raw(10).unwrap().to_decimal_str(), "0.00000000001");
+        assert_eq!(Fixed::from_raw(SCALE + 1).unwrap().to_decimal_str(), "1.000000000001");
+        assert_eq!(Fixed::from_raw(DECAY_FACTOR_SCALED).unwrap().to_decimal_str(), "0.943932824245");
+    }
+
+    #[test]
+    fn from_str_parses_decimal_units() {
+        assert_eq!("1.5".parse::<Fixed>(), Ok(Fixed::from_raw(1_500_000_000_000).unwrap()));
+        assert_eq!("1.50".parse::<Fixed>(), "1.5".parse::<Fixed>());
+        assert_eq!("7".parse::<Fixed>(), Fixed::from_units(7));
+        assert_eq!("0.000000000001".parse::<Fixed>(), Fixed::from_raw(1));
+        for bad in ["", ".5", "1.", "01.5", "-1", "1.5e3", "1.0000000000001", "1,5"] {
+            assert_eq!(bad.parse::<Fixed>(), Err(TransitionError::InvalidSerialization), "{:?}", bad);
+        }
+        let max = Fixed::from_raw(MAX_SAFE_BALANCE_RAW).unwrap();
+        assert_eq!(max.to_string().parse::<Fixed>(), Ok(max));
+        assert_eq!(format!("{}0", max.split_units().0).parse::<Fixed>(), Err(TransitionError::MathOverflow));
+    }
+
+    #[test]
+    fn decimal_units_fixtures_parse_exactly() {
+        use crate::state::decay::DECAY_FACTOR_SCALED;
+
+        assert_eq!(Fixed::from_decimal_str("0.943932824245"), Fixed::from_raw(DECAY_FACTOR_SCALED));
+        assert_eq!(Fixed::from_decimal_str("0"), Ok(Fixed::zero()));
+        assert_eq!(Fixed::from_decimal_str("0.5"), Fixed::from_raw(SCALE / 2));
+        for bad in ["00", "00.5", "+1", "0.", " 1", "1 "] {
+            assert_eq!(Fixed::from_decimal_str(bad), Err(TransitionError::InvalidSerialization), "{:?}", bad);
+        }
+        // Integer part alone overflows u128 before scaling.
+        assert_eq!(Fixed::from_decimal_str(&u128::MAX.to_string()), Err(TransitionError::MathOverflow));
+        assert_eq!(Fixed::from_decimal_str("340282366920938463463374607431768211456"), Err(TransitionError::MathOverflow));
+    }
 }
@@ -0,0 +1,142 @@
+//! `FixedN<SCALE_POW10>` — fixed-point with a configurable power-of-ten scale.
+//!
+//! RESEARCH PATH ONLY. This type exists so simulation code can explore
+//! precision tradeoffs without forking the constitutional type. Consensus code
+//! uses `math::fixed::Fixed`, whose scale is frozen at 10^12; `FixedN<12>` is
+//! its conceptual equivalent but is NOT interchangeable with it.
+//!
+//! The arithmetic mirrors `Fixed` exactly: checked multiply before the scale
+//! reduction, floor truncation, and a zero-denominator pre-check.
+//! `SCALE_POW10` must be at most 38 (10^39 overflows u128); larger values
+//! fail at compile time when the scale is first used.
+
+use crate::TransitionError;
+
+/// Fixed-point value with `SCALE = 10^SCALE_POW10`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct FixedN<const SCALE_POW10: u32>(u128);
+
+impl<const SCALE_POW10: u32> FixedN<SCALE_POW10> {
+    /// The scaling factor. 1.0 unit = FixedN(SCALE).
+    pub const SCALE: u128 = 10u128.pow(SCALE_POW10);
+
+    /// Largest raw value that cannot overflow a subsequent `mul_scaled` by 1.0.
+    /// Derived as: u128::MAX / SCALE (same rule as `MAX_SAFE_BALANCE_RAW`).
+    pub const MAX_SAFE_RAW: u128 = u128::MAX / Self::SCALE;
+
+    /// Construct from a pre-scaled raw u128.
+    /// Returns MathOverflow if the raw value exceeds MAX_SAFE_RAW.
+    pub fn from_raw(raw: u128) -> Result<Self, TransitionError> {
+        if raw > Self::MAX_SAFE_RAW {
+            Err(TransitionError::MathOverflow)
+        } else {
+            Ok(FixedN(raw))
+        }
+    }
+
+    /// Construct from a whole-unit count. Equivalent to `from_raw(whole_units * SCALE)`.
+    pub fn from_units(whole_units: u128) -> Result<Self, TransitionError> {
+        let raw = whole_units.checked_mul(Self::SCALE).ok_or(TransitionError::MathOverflow)?;
+        Self::from_raw(raw)
+    }
+
+    /// Returns the inner raw u128 value.
+    pub fn raw(self) -> u128 {
+        self.0
+    }
+
+    /// Multiply, dividing by SCALE to keep the result scaled (floor).
+    pub fn mul_scaled(self, other: Self) -> Result<Self, TransitionError> {
+        let product = self.0.checked_mul(other.0).ok_or(TransitionError::MathOverflow)?;
+        Self::from_raw(product / Self::SCALE)
+    }
+
+    /// Divide: (self * SCALE) / other, floor. Pre-checks the denominator for zero.
+    pub fn div_scaled(self, other: Self) -> Result<Self, TransitionError> {
+        if other.0 == 0 {
+            return Err(TransitionError::DivisionByZero);
+        }
+        let numerator = self.0.checked_mul(Self::SCALE).ok_or(TransitionError::MathOverflow)?;
+        Self::from_raw(numerator / other.0)
+    }
+
+    /// Add. Returns MathOverflow if the result exceeds MAX_SAFE_RAW.
+    pub fn checked_add(self, other: Self) -> Result<Self, TransitionError> {
+        let sum = self.0.checked_add(other.0).ok_or(TransitionError::MathOverflow)?;
+        Self::from_raw(sum)
+    }
+
+    /// Subtract. Returns MathUnderflow if other > self.
+    pub fn checked_sub(self, other: Self) -> Result<Self, TransitionError> {
+        let diff = self.0.checked_sub(other.0).ok_or(TransitionError::MathUnderflow)?;
+        Ok(FixedN(diff))
+    }
+
+    /// Returns the zero value.
+    pub fn zero() -> Self {
+        FixedN(0)
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests — use std only here
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::fixed::{Fixed, SCALE};
+
+    type Micro = FixedN<6>;
+    type Atto = FixedN<18>;
+
+    #[test]
+    fn micro_scale_multiply_reduces_once() {
+        // 2.5 * 4.0 = 10.0 at 10^6.
+        let a = Micro::from_raw(2_500_000).unwrap();
+        let b = Micro::from_units(4).unwrap();
+        assert_eq!(Micro::SCALE, 1_000_000);
+        assert_eq!(a.mul_scaled(b).unwrap(), Micro::from_units(10).unwrap());
+        // 0.000001 * 0.5 truncates to zero at this precision.
+        let tiny = Micro::from_raw(1).unwrap();
+        let half = Micro::from_raw(500_000).unwrap();
+        assert_eq!(tiny.mul_scaled(half).unwrap(), Micro::zero());
+    }
+
+    #[test]
+    fn atto_scale_multiply_reduces_once() {
+        // 1.5 * 3.0 = 4.5 at 10^18.
+        let a = Atto::from_raw(1_500_000_000_000_000_000).unwrap();
+        let b = Atto::from_units(3).unwrap();
+        assert_eq!(Atto::SCALE, 1_000_000_000_000_000_000);
+        assert_eq!(a.mul_scaled(b).unwrap().raw(), 4_500_000_000_000_000_000);
+        // Precision finer than 10^12 survives: 10^-15 * 2.0 = 2 * 10^-15.
+        let f = Atto::from_raw(1_000).unwrap();
+        assert_eq!(f.mul_scaled(Atto::from_units(2).unwrap()).unwrap().raw(), 2_000);
+    }
+
+    #[test]
+    fn atto_scale_has_smaller_headroom() {
+        assert_eq!(Atto::MAX_SAFE_RAW, u128::MAX / 1_000_000_000_000_000_000);
+        assert_eq!(Atto::from_units(u128::MAX / Atto::SCALE), Err(TransitionError::MathOverflow));
+    }
+
+    #[test]
+    fn div_scaled_matches_scale() {
+        let one = Micro::from_units(1).unwrap();
+        let three = Micro::from_units(3).unwrap();
+        assert_eq!(one.div_scaled(three).unwrap().raw(), 333_333);
+        assert_eq!(one.div_scaled(Micro::zero()), Err(TransitionError::DivisionByZero));
+    }
+
+    #[test]
+    fn pow12_agrees_with_constitutional_fixed() {
+        type Twelve = FixedN<12>;
+        assert_eq!(Twelve::SCALE, SCALE);
+        let a = Twelve::from_raw(7_123_456_789_012).unwrap();
+        let b = Twelve::from_raw(3_987_654_321_098).unwrap();
+        let fa = Fixed::from_raw(7_123_456_789_012).unwrap();
+        let fb = Fixed::from_raw(3_987_654_321_098).unwrap();
+        assert_eq!(a.mul_scaled(b).unwrap().raw(), fa.mul_scaled(fb).unwrap().raw());
+        assert_eq!(a.div_scaled(b).unwrap().raw(), fa.div_scaled(fb).unwrap().raw());
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! Module layout:
 //!   math::fixed    — The Fixed(u128) wrapper. Private inner value.
-//!   math::sqrt     — Constitutional integer square root (Babylonian, floor-rounded).
+//!   math::sqrt     — Constitutional integer square root (Babylonian, floor-rounded), cube root and n-th root.
 //!   math::overflow — Checked arithmetic combinators used by the rest of the kernel.
+//!   math::fixed_generic — FixedN<SCALE_POW10>: configurable-scale research type. Non-consensus.
 
 pub mod fixed;
 pub mod sqrt;
 pub mod overflow;
+pub mod fixed_generic;
@@ -23,7 +23,222 @@ pub fn checked_add_raw(a: u128, b: u128) -> Result<u128, TransitionError> {
     a.checked_add(b).ok_or(TransitionError::MathOverflow)
 }
 
-/// Subtract raw b from raw a with underflow check.
+/// Sum a slice of raw u128 values with overflow check. The empty sum is 0.
+pub fn checked_sum_raw(values: &[u128]) -> Result<u128, TransitionError> {
+    checked_sum_raw_indexed(values).map_err(|(e, _)| e)
+}
+
+/// `checked_sum_raw`, additionally reporting the index of the first element
+/// whose addition overflowed, for diagnosing malformed aggregations.
+pub fn checked_sum_raw_indexed(values: &[u128]) -> Result<u128, (TransitionError, usize)> {
+    let mut total: u128 = 0;
+    for (i, &v) in values.iter().enumerate() {
+        total = checked_add_raw(total, v).map_err(|e| (e, i))?;
+    }
+    Ok(total)
+}
+
+/// Subtract raw b from raw a with underflow check. Returns `MathUnderflow` if b > a.
 pub fn checked_sub_raw(a: u128, b: u128) -> Result<u128, TransitionError> {
-    a.checked_sub(b).ok_or(TransitionError::MathOverflow)
+    a.checked_sub(b).ok_or(TransitionError::MathUnderflow)
+}
+
+/// Raise raw `base` to `exp` by exponentiation by squaring, every multiply
+/// checked. Returns `MathOverflow` only when `base^exp` exceeds `u128::MAX`:
+/// the base is squared only while a higher exponent bit remains, so an unused
+/// square never overflows. `checked_pow_raw(0, 0)` is 1.
+pub fn checked_pow_raw(base: u128, exp: u32) -> Result<u128, TransitionError> {
+    let (mut base, mut exp) = (base, exp);
+    let mut result: u128 = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = checked_mul_raw(result, base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = checked_mul_raw(base, base)?;
+        }
+    }
+    Ok(result)
+}
+
+/// Compute `floor(a * b / c)` exactly, with a 256-bit intermediate product.
+///
+/// Unlike `checked_mul_raw` followed by `checked_div_raw`, the product itself
+/// never overflows; only a quotient above `u128::MAX` returns `MathOverflow`.
+/// Returns `DivisionByZero` if `c` is zero.
+pub fn checked_mul_div_raw(a: u128, b: u128, c: u128) -> Result<u128, TransitionError> {
+    if c == 0 {
+        return Err(TransitionError::DivisionByZero);
+    }
+    let (hi, lo) = mul_wide(a, b);
+    if hi >= c {
+        // The quotient would need more than 128 bits.
+        return Err(TransitionError::MathOverflow);
+    }
+    // Restoring long division of (hi, lo) by c, one bit of `lo` at a time.
+    // Invariant: remainder < c, so the quotient fits in 128 bits.
+    let mut remainder = hi;
+    let mut quotient: u128 = 0;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            quotient |= 1;
+        }
+    }
+    Ok(quotient)
+}
+
+/// Full 256-bit product of two u128 values as `(high, low)` halves.
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    // Middle column: high half of p00 plus the low halves of the cross terms.
+    // Each term is < 2^64, so the sum is < 3 * 2^64 and cannot overflow.
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Saturating variants — HOST TELEMETRY ONLY
+// ──────────────────────────────────────────────────────────────────────────────
+//
+// FORBIDDEN IN CONSENSUS PATHS. Clamping silently discards information, so two
+// nodes could agree on a clamped value while disagreeing on the true one.
+// Consensus code uses the checked_* variants above and surfaces MathOverflow.
+// These exist for host-side metrics aggregation (dashboards, counters) only.
+
+/// Add two raw u128 values, clamping at `u128::MAX`. Telemetry only.
+pub fn saturating_add_raw(a: u128, b: u128) -> u128 {
+    a.saturating_add(b)
+}
+
+/// Multiply two raw u128 values, clamping at `u128::MAX`. Telemetry only.
+pub fn saturating_mul_raw(a: u128, b: u128) -> u128 {
+    a.saturating_mul(b)
+}
+
+/// Subtract raw b from raw a, clamping at zero. Telemetry only.
+pub fn saturating_sub_raw(a: u128, b: u128) -> u128 {
+    a.saturating_sub(b)
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexed_sum_reports_overflowing_element() {
+        let values = [u128::MAX - 10, 5, 6, 1];
+        assert_eq!(checked_sum_raw_indexed(&values), Err((TransitionError::MathOverflow, 2)));
+        assert_eq!(checked_sum_raw(&values), Err(TransitionError::MathOverflow));
+        assert_eq!(checked_sum_raw_indexed(&values[..2]), Ok(u128::MAX - 5));
+        assert_eq!(checked_sum_raw(&[]), Ok(0));
+    }
+
+    #[test]
+    fn saturating_add_clamps_at_max() {
+        assert_eq!(saturating_add_raw(u128::MAX - 1, 1), u128::MAX);
+        assert_eq!(saturating_add_raw(u128::MAX, 1), u128::MAX);
+        assert_eq!(saturating_add_raw(2, 3), 5);
+    }
+
+    #[test]
+    fn saturating_mul_clamps_at_max() {
+        assert_eq!(saturating_mul_raw(u128::MAX / 2, 2), u128::MAX - 1);
+        assert_eq!(saturating_mul_raw(u128::MAX / 2 + 1, 2), u128::MAX);
+        assert_eq!(saturating_mul_raw(6, 7), 42);
+    }
+
+    #[test]
+    fn saturating_sub_clamps_at_zero() {
+        assert_eq!(saturating_sub_raw(1, 1), 0);
+        assert_eq!(saturating_sub_raw(1, 2), 0);
+        assert_eq!(saturating_sub_raw(0, u128::MAX), 0);
+        assert_eq!(saturating_sub_raw(10, 3), 7);
+    }
+
+    #[test]
+    fn checked_variants_still_reject_where_saturating_clamps() {
+        assert_eq!(checked_add_raw(u128::MAX, 1), Err(TransitionError::MathOverflow));
+        assert_eq!(checked_sub_raw(1, 2), Err(TransitionError::MathUnderflow));
+    }
+
+    #[test]
+    fn underflow_and_overflow_are_distinct() {
+        assert_eq!(checked_sub_raw(0, 1), Err(TransitionError::MathUnderflow));
+        assert_eq!(checked_add_raw(u128::MAX, 1), Err(TransitionError::MathOverflow));
+        assert_eq!(checked_mul_raw(u128::MAX, 2), Err(TransitionError::MathOverflow));
+        assert_eq!(checked_sub_raw(5, 5), Ok(0));
+    }
+
+    #[test]
+    fn checked_pow_raw_vectors() {
+        assert_eq!(checked_pow_raw(2, 127), Ok(1u128 << 127));
+        assert_eq!(checked_pow_raw(10, 38), Ok(100_000_000_000_000_000_000_000_000_000_000_000_000));
+        assert_eq!(checked_pow_raw(2, 128), Err(TransitionError::MathOverflow));
+        assert_eq!(checked_pow_raw(10, 39), Err(TransitionError::MathOverflow));
+        // A large base to the first power must not square itself.
+        assert_eq!(checked_pow_raw(u128::MAX, 1), Ok(u128::MAX));
+        assert_eq!(checked_pow_raw(1u128 << 64, 1), Ok(1u128 << 64));
+        assert_eq!(checked_pow_raw(0, 0), Ok(1));
+        assert_eq!(checked_pow_raw(1, u32::MAX), Ok(1));
+        for base in [0u128, 3, 7, 1_000_000_007] {
+            for exp in 0..20u32 {
+                assert_eq!(checked_pow_raw(base, exp).ok(), base.checked_pow(exp), "{}^{}", base, exp);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_div_survives_intermediate_overflow() {
+        // u128::MAX * u128::MAX / u128::MAX: the product needs 256 bits.
+        assert_eq!(checked_mul_div_raw(u128::MAX, u128::MAX, u128::MAX), Ok(u128::MAX));
+        assert_eq!(checked_mul_div_raw(u128::MAX, 3, 4), Ok(u128::MAX / 4 * 3 + 2));
+        assert_eq!(checked_mul_div_raw(1u128 << 100, 1u128 << 100, 1u128 << 90), Ok(1u128 << 110));
+    }
+
+    #[test]
+    fn mul_div_succeeds_where_checked_mul_then_div_fails() {
+        const SCALE: u128 = 1_000_000_000_000;
+        // Emission-shaped: a ceiling-sized bond times a long lock, over SCALE.
+        let (bond_raw, epochs) = (u128::MAX / SCALE, 10_000_000_000_000u128);
+        assert_eq!(checked_mul_raw(bond_raw, epochs), Err(TransitionError::MathOverflow));
+        assert_eq!(checked_mul_div_raw(bond_raw, epochs, SCALE), Ok(bond_raw / SCALE * epochs + (bond_raw % SCALE) * epochs / SCALE));
+
+        for (a, b, c) in [(u128::MAX, u128::MAX, u128::MAX), (1u128 << 127, 4, 8), (u128::MAX, SCALE, SCALE)] {
+            assert_eq!(checked_mul_raw(a, b), Err(TransitionError::MathOverflow));
+            assert!(checked_mul_div_raw(a, b, c).is_ok(), "{} * {} / {}", a, b, c);
+        }
+    }
+
+    #[test]
+    fn mul_div_matches_narrow_arithmetic_and_floors() {
+        assert_eq!(checked_mul_div_raw(7, 5, 3), Ok(11));
+        assert_eq!(checked_mul_div_raw(0, u128::MAX, 1), Ok(0));
+        assert_eq!(checked_mul_div_raw(123_456_789, 1_000_000_000_000, 7), Ok(123_456_789 * 1_000_000_000_000 / 7));
+    }
+
+    #[test]
+    fn mul_div_rejects_zero_divisor_and_wide_quotient() {
+        assert_eq!(checked_mul_div_raw(1, 1, 0), Err(TransitionError::DivisionByZero));
+        assert_eq!(checked_mul_div_raw(u128::MAX, 2, 1), Err(TransitionError::MathOverflow));
+        assert_eq!(checked_mul_div_raw(u128::MAX, 2, 2), Ok(u128::MAX));
+        assert_eq!(checked_mul_raw(u128::MAX, 2), Err(TransitionError::MathOverflow));
+    }
 }
@@ -14,6 +14,20 @@
 //!
 //! The test vectors below are CONSTITUTIONAL. Any implementation that does not
 //! produce these exact outputs for these exact inputs is non-compliant.
+//!
+//! CONVERGENCE BOUND:
+//! The initial estimate 2^ceil(bits/2) is always >= sqrt(n) and < 2*sqrt(n), so
+//! the relative error starts below 1 and Newton's method squares it every step.
+//! Six steps drive the error below one unit for any u128 input; one more step
+//! absorbs floor truncation and one final pass detects convergence.
+//! Debug builds assert the loop never exceeds `ISQRT_MAX_ITERATIONS` passes.
+//! Release builds compile the counter out entirely.
+
+use crate::TransitionError;
+
+/// Upper bound on Babylonian loop passes for any u128 input (including the
+/// final pass that detects convergence). Asserted in debug builds only.
+pub const ISQRT_MAX_ITERATIONS: u32 = 8;
 
 /// Returns floor(sqrt(n)) for any u128 input.
 /// Uses the Babylonian (Newton's method) integer convergence.
@@ -29,8 +43,18 @@ pub fn isqrt(n: u128) -> u128 {
     // Initial estimate: use bit-length to start near the answer.
     // This avoids the slow convergence that occurs when starting from n itself.
     let bits = 128 - n.leading_zeros();
-    let mut x = 1u128 << ((bits + 1) / 2);
+    let mut x = 1u128 << bits.div_ceil(2);
+    #[cfg(debug_assertions)]
+    let mut iterations: u32 = 0;
     loop {
+        #[cfg(debug_assertions)]
+        {
+            iterations += 1;
+            debug_assert!(
+                iterations <= ISQRT_MAX_ITERATIONS,
+                "isqrt({}) exceeded the proven convergence bound", n
+            );
+        }
         // Babylonian step: next = (x + n/x) / 2
         // Integer division here truncates — this is intentional and constitutional.
         let next = (x + n / x) / 2;
@@ -42,6 +66,82 @@ pub fn isqrt(n: u128) -> u128 {
     }
 }
 
+/// Returns `(isqrt(n), n - isqrt(n)^2)`. The remainder is 0 exactly when `n`
+/// is a perfect square, and is at most `2 * root` (else `root + 1` would fit).
+pub fn isqrt_rem(n: u128) -> (u128, u128) {
+    let root = isqrt(n);
+    // root^2 <= n, so neither the square nor the difference can overflow.
+    (root, n - root * root)
+}
+
+/// Returns floor(cbrt(n)) for any u128 input: `r^3 <= n < (r + 1)^3`.
+///
+/// Integer Newton iteration `next = (2x + n / x^2) / 3` from `2^ceil(bits/3)`,
+/// which is never below the root, so the sequence falls monotonically to the
+/// floor. `x <= 2^43` keeps `x^2` and `2x` far inside u128. An exact
+/// post-correction loop then enforces the bracket directly, so the result does
+/// not rest on the convergence argument alone. Equals `iroot(n, 3)`.
+///
+/// Edge cases:
+///   icbrt(0) = 0
+///   icbrt(u128::MAX) = 6_981_463_658_331
+pub fn icbrt(n: u128) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+    let bits = 128 - n.leading_zeros();
+    let mut x = 1u128 << bits.div_ceil(3);
+    loop {
+        let next = (2 * x + n / (x * x)) / 3;
+        if next >= x {
+            break;
+        }
+        x = next;
+    }
+    while x.checked_pow(3).is_none_or(|c| c > n) {
+        x -= 1;
+    }
+    while (x + 1).checked_pow(3).is_some_and(|c| c <= n) {
+        x += 1;
+    }
+    x
+}
+
+/// Returns floor(n^(1/degree)) by integer Newton's method.
+///
+/// `degree == 1` returns `n`; `degree == 2` equals `isqrt(n)` for every input
+/// (`isqrt` keeps its own pinned loop and convergence bound). `degree == 0` has
+/// no root and returns `DivisionByZero`.
+///
+/// The step is `next = ((degree - 1) * x + n / x^(degree - 1)) / degree`, from an
+/// initial estimate `2^ceil(bits/degree)` that is never below the root. A power
+/// `x^(degree - 1)` that overflows u128 exceeds `n`, so its quotient is 0; a step
+/// sum that overflows is far above `x` and ends the loop. Integer ops only.
+pub fn iroot(n: u128, degree: u32) -> Result<u128, TransitionError> {
+    match degree {
+        0 => return Err(TransitionError::DivisionByZero),
+        1 => return Ok(n),
+        _ => {}
+    }
+    if n == 0 {
+        return Ok(0);
+    }
+    let k = degree as u128;
+    let bits = 128 - n.leading_zeros();
+    let mut x = 1u128 << bits.div_ceil(degree);
+    loop {
+        let quotient = x.checked_pow(degree - 1).map_or(0, |p| n / p);
+        let next = match ((k - 1) * x).checked_add(quotient) {
+            Some(sum) => sum / k,
+            None => return Ok(x),
+        };
+        if next >= x {
+            return Ok(x);
+        }
+        x = next;
+    }
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // Constitutional test vectors.
 // These values are FROZEN. They define what "correct sqrt" means for this kernel.
@@ -85,4 +185,119 @@ mod tests {
         assert_eq!(isqrt(0), 0);
         assert_eq!(isqrt(1), 1);
     }
+
+    #[test]
+    fn convergence_bound_holds() {
+        // Debug builds assert the iteration bound inside isqrt itself, so every
+        // call below would panic if the loop ever ran past ISQRT_MAX_ITERATIONS.
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(u128::MAX), 18_446_744_073_709_551_615u128);
+
+        // Values just below perfect squares are the slowest to converge:
+        // floor must land one below the exact root.
+        for r in [2u128, 3, 1_000_000, 4_294_967_296, 18_446_744_073_709_551_615] {
+            let sq = r * r;
+            assert_eq!(isqrt(sq - 1), r - 1, "isqrt({}^2 - 1) should be {}", r, r - 1);
+            assert_eq!(isqrt(sq), r);
+        }
+
+        // Every power of two and its neighbours: covers every bit length.
+        for shift in 0..128u32 {
+            let p = 1u128 << shift;
+            let _ = isqrt(p);
+            let _ = isqrt(p - 1);
+            let _ = isqrt(p.saturating_add(1));
+        }
+    }
+
+    #[test]
+    fn isqrt_rem_vectors() {
+        // Perfect squares: remainder 0.
+        assert_eq!(isqrt_rem(0), (0, 0));
+        assert_eq!(isqrt_rem(1), (1, 0));
+        assert_eq!(isqrt_rem(100), (10, 0));
+        assert_eq!(isqrt_rem(1_000_000_000_000u128), (1_000_000, 0));
+        // Non-squares.
+        assert_eq!(isqrt_rem(2), (1, 1));
+        assert_eq!(isqrt_rem(10), (3, 1));
+        assert_eq!(isqrt_rem(99), (9, 18));
+        // u128::MAX = (2^64 - 1)^2 + 2 * (2^64 - 1): the largest possible remainder.
+        let r = 18_446_744_073_709_551_615u128;
+        assert_eq!(isqrt_rem(u128::MAX), (r, 2 * r));
+    }
+
+    #[test]
+    fn icbrt_constitutional_vectors() {
+        assert_eq!(icbrt(0), 0);
+        assert_eq!(icbrt(1), 1);
+        assert_eq!(icbrt(7), 1);
+        assert_eq!(icbrt(8), 2);
+        assert_eq!(icbrt(26), 2);
+        assert_eq!(icbrt(27), 3);
+        // SCALE = 10^12: icbrt(SCALE) = 10^4.
+        assert_eq!(icbrt(1_000_000_000_000u128), 10_000);
+        // 6_981_463_658_331^3 <= 2^128 - 1 < 6_981_463_658_332^3.
+        assert_eq!(icbrt(u128::MAX), 6_981_463_658_331);
+    }
+
+    #[test]
+    fn icbrt_brackets_and_matches_iroot() {
+        for r in [1u128, 2, 10, 1_000_000, 6_981_463_658_331] {
+            let c = r * r * r;
+            assert_eq!(icbrt(c), r);
+            assert_eq!(icbrt(c - 1), r - 1);
+        }
+        let mut x = 0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C834u128;
+        for i in 0..2_000u128 {
+            x = x.rotate_left(13) ^ x.wrapping_mul(0x2545_F491_4F6C_DD1D);
+            for n in [i, (1u128 << (i % 128)).wrapping_sub(1), x, x >> (i % 128)] {
+                let r = icbrt(n);
+                assert!(r.pow(3) <= n && (r + 1).checked_pow(3).is_none_or(|c| c > n), "n = {}", n);
+                assert_eq!(Ok(r), iroot(n, 3), "n = {}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn iroot_vectors() {
+        assert_eq!(iroot(16, 4), Ok(2));
+        assert_eq!(iroot(17, 4), Ok(2));
+        assert_eq!(iroot(15, 4), Ok(1));
+        assert_eq!(iroot(1_000_000_000_000, 2), Ok(1_000_000));
+        assert_eq!(iroot(1_000_000_000_000, 3), Ok(10_000));
+        assert_eq!(iroot(26, 3), Ok(2));
+        assert_eq!(iroot(27, 3), Ok(3));
+        assert_eq!(iroot(u128::MAX, 3), Ok(6_981_463_658_331));
+        assert_eq!(iroot(u128::MAX, 64), Ok(3));
+        assert_eq!(iroot(u128::MAX, 128), Ok(1));
+        assert_eq!(iroot(u128::MAX, u32::MAX), Ok(1));
+        assert_eq!(iroot(12_345, 1), Ok(12_345));
+        assert_eq!(iroot(0, 7), Ok(0));
+        assert_eq!(iroot(5, 0), Err(TransitionError::DivisionByZero));
+    }
+
+    #[test]
+    fn iroot_degree_two_matches_isqrt() {
+        let mut x = 0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C834u128;
+        for i in 0..2_000u128 {
+            // Small, near-boundary, and scrambled full-width inputs.
+            x = x.rotate_left(13) ^ x.wrapping_mul(0x2545_F491_4F6C_DD1D);
+            for n in [i, (1u128 << (i % 128)).wrapping_sub(1), x, x >> (i % 128)] {
+                assert_eq!(iroot(n, 2), Ok(isqrt(n)), "n = {}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn iroot_is_floor_for_higher_degrees() {
+        let mut x = 0x0123_4567_89AB_CDEFu128;
+        for _ in 0..500 {
+            x = x.rotate_left(29) ^ x.wrapping_mul(0x2545_F491_4F6C_DD1D);
+            for degree in 3..=9u32 {
+                let r = iroot(x, degree).unwrap();
+                assert!(r.pow(degree) <= x);
+                assert!((r + 1).checked_pow(degree).is_none_or(|p| p > x), "{}^(1/{})", x, degree);
+            }
+        }
+    }
 }
@@ -18,6 +18,7 @@
 //! 8. Maximum input size: `MAX_INPUT_BYTES` (65 536 = 64 KiB).
 //! 9. BOM is rejected. Trailing content after the root value is rejected.
 //! 10. Raw control characters (U+0000..U+001F) in string values are rejected.
+//! 11. Maximum total values in one document: `MAX_TOTAL_NODES` (16 384).
 //!
 //! # Architecture
 //!
@@ -25,8 +26,20 @@
 //!
 //! Internally:
 //! 1. Parse: hand-written recursive-descent parser → `Value` tree.
+//!    Recursion is bounded: `parse_object`/`parse_array` check `MAX_DEPTH`
+//!    immediately after consuming the opening bracket, before any nested
+//!    `parse_value` call, so the native stack never holds more than
+//!    `MAX_DEPTH` container frames (plus one `parse_value` frame each) no
+//!    matter how deep the input claims to nest. The emitter recurses over
+//!    the already-bounded tree.
 //! 2. Validate: all constraints enforced during parse (no second pass).
 //! 3. Emit: deterministic byte emitter with sorted object keys.
+//!
+//! `parse(input)` exposes the validated `Value` tree for read-only inspection.
+//! `CanonicalObject` builds new canonical objects; its output is re-checked
+//! through `canonicalize` before it is returned.
+//! `validate_with_path(bytes, &Schema)` checks a nested shape and names the
+//! first violating value by JSON Pointer, for debugging host payloads.
 
 use crate::TransitionError;
 
@@ -46,13 +59,22 @@ pub const MAX_ARRAY_ITEMS: usize = 1_024;
 /// Maximum total input size in bytes.
 pub const MAX_INPUT_BYTES: usize = 65_536;
 
+/// Maximum number of values (objects, arrays, strings, literals — the root
+/// included) in one document, counted as a running total during parse.
+///
+/// The per-structure limits above still allow shapes whose product is large;
+/// this caps total parser memory regardless of shape. Set to
+/// `MAX_INPUT_BYTES / 4`: any document averaging at least 4 bytes per value
+/// (e.g. `"",` or `[],` plus any key) is bounded by the byte cap first.
+pub const MAX_TOTAL_NODES: usize = MAX_INPUT_BYTES / 4;
+
 // ──────────────────────────────────────────────────────────────────────────────
-// Internal value tree
+// Value tree
 // ──────────────────────────────────────────────────────────────────────────────
 
 /// A parsed JSON value. JSON number literals are absent — they are forbidden.
-#[derive(Debug)]
-enum Value {
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
     Null,
     Bool(bool),
     /// String: decoded content stored as raw UTF-8 bytes.
@@ -63,6 +85,35 @@ enum Value {
     Object(Vec<(Vec<u8>, Value)>),
 }
 
+impl Value {
+    /// Look up `key` in an object. `None` for a missing key or a non-object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(pairs) => pairs
+                .iter()
+                .find(|(k, _)| k == key.as_bytes())
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// The decoded bytes of a string value. `None` for any other variant.
+    pub fn as_str_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::Str(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// The items of an array value. `None` for any other variant.
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // Parser
 // ──────────────────────────────────────────────────────────────────────────────
@@ -71,11 +122,12 @@ struct Parser {
     src: Vec<u8>,
     pos: usize,
     depth: usize,
+    nodes: usize,
 }
 
 impl Parser {
     fn new(src: Vec<u8>) -> Self {
-        Parser { src, pos: 0, depth: 0 }
+        Parser { src, pos: 0, depth: 0, nodes: 0 }
     }
 
     #[inline(always)]
@@ -108,6 +160,10 @@ impl Parser {
     }
 
     fn parse_value(&mut self) -> Result<Value, TransitionError> {
+        self.nodes += 1;
+        if self.nodes > MAX_TOTAL_NODES {
+            return Err(TransitionError::InvalidSerialization);
+        }
         self.skip_whitespace();
         match self.peek() {
             Some(b'"') => self.parse_string().map(Value::Str),
@@ -232,7 +288,7 @@ impl Parser {
 
             // Key must match ^[a-z][a-z0-9_]*$ — lowercase ASCII only.
             // First byte must be a letter (not digit or underscore).
-            if !matches!(key[0], b'a'..=b'z') {
+            if !key[0].is_ascii_lowercase() {
                 return Err(TransitionError::InvalidSerialization);
             }
             for &b in &key[1..] {
@@ -389,6 +445,15 @@ fn emit(value: &Value, out: &mut Vec<u8>) {
 ///
 /// This function is pure: no I/O, no randomness, no environment reads, no clock.
 pub fn canonicalize(input: &[u8]) -> Result<Vec<u8>, TransitionError> {
+    let value = parse(input)?;
+    let mut out = Vec::with_capacity(input.len());
+    emit(&value, &mut out);
+    Ok(out)
+}
+
+/// Parse JSON input into a `Value` tree, enforcing every constitutional rule
+/// that `canonicalize` enforces. `canonicalize(x)` is `parse(x)` then emit.
+pub fn parse(input: &[u8]) -> Result<Value, TransitionError> {
     if input.len() > MAX_INPUT_BYTES {
         return Err(TransitionError::InvalidSerialization);
     }
@@ -406,9 +471,64 @@ pub fn canonicalize(input: &[u8]) -> Result<Vec<u8>, TransitionError> {
         return Err(TransitionError::InvalidSerialization);
     }
 
-    let mut out = Vec::with_capacity(input.len());
-    emit(&value, &mut out);
-    Ok(out)
+    Ok(value)
+}
+
+/// Builder for a canonical JSON object.
+///
+/// Fields may be added in any order; output keys are sorted on `finish`.
+/// `finish` re-runs the result through `canonicalize`, so bad keys, duplicate
+/// keys, and size-limit violations are reported exactly as for parsed input.
+#[derive(Clone, Debug, Default)]
+pub struct CanonicalObject {
+    pairs: Vec<(Vec<u8>, Value)>,
+}
+
+impl CanonicalObject {
+    /// An empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a string field. `value` is raw bytes; escaping happens on emit.
+    pub fn string(self, key: &str, value: &[u8]) -> Self {
+        self.value(key, Value::Str(value.to_vec()))
+    }
+
+    /// Add a field holding an arbitrary `Value` (nested object, array, null...).
+    pub fn value(mut self, key: &str, value: Value) -> Self {
+        self.pairs.push((key.as_bytes().to_vec(), value));
+        self
+    }
+
+    /// Convert into a `Value::Object` for nesting inside another builder.
+    pub fn into_value(self) -> Value {
+        Value::Object(self.pairs)
+    }
+
+    /// Emit the canonical bytes, verified by a `canonicalize` round-trip.
+    pub fn finish(self) -> Result<Vec<u8>, TransitionError> {
+        let mut out = Vec::new();
+        emit(&Value::Object(self.pairs), &mut out);
+        let checked = canonicalize(&out)?;
+        if checked != out {
+            return Err(TransitionError::InvalidSerialization);
+        }
+        Ok(out)
+    }
+}
+
+/// Canonicalize JSON input and report the exact output length.
+///
+/// Contract: the returned bytes end on the last byte of the root value — a
+/// structural character (`}`, `]`, `"`) or the final letter of a literal
+/// (`null`, `true`, `false`). There is never trailing whitespace or a newline,
+/// so the bytes can be hashed or length-prefixed as-is. The length is always
+/// `bytes.len()`; it is returned so framing code need not re-derive it.
+pub fn canonicalize_sized(input: &[u8]) -> Result<(Vec<u8>, usize), TransitionError> {
+    let bytes = canonicalize(input)?;
+    let len = bytes.len();
+    Ok((bytes, len))
 }
 
 /// Validate that a canonical JSON object contains exactly the set of `allowed_keys`.
@@ -416,13 +536,17 @@ pub fn canonicalize(input: &[u8]) -> Result<Vec<u8>, TransitionError> {
 /// Called AFTER `canonicalize`. Rejects objects with extra keys OR missing keys.
 /// Schema enforcement is separate from canonicalization: first canonicalize,
 /// then call this function for each expected payload type.
+///
+/// A root that is not an object (array, string, bool, null) is rejected with
+/// `InvalidSerialization`, as is any input `parse` rejects (including trailing
+/// content after the root).
 pub fn validate_schema(
     canonical: &[u8],
     allowed_keys: &[&str],
 ) -> Result<(), TransitionError> {
-    // Re-parse the canonical bytes (already validated, so this is cheap).
-    let mut parser = Parser::new(canonical.to_vec());
-    let value = parser.parse_value().map_err(|_| TransitionError::InvalidSerialization)?;
+    // Re-parse the canonical bytes with the full rule set (already validated,
+    // so this is cheap). Every parse failure surfaces as InvalidSerialization.
+    let value = parse(canonical).map_err(|_| TransitionError::InvalidSerialization)?;
 
     let pairs = match value {
         Value::Object(pairs) => pairs,
@@ -451,6 +575,9 @@ pub fn validate_schema(
 
 /// Validate that a string value matches the numeric-string protocol:
 /// `^(0|[1-9][0-9]*)$` — no leading zeros, no sign prefix, no decimal, no exponent.
+///
+/// `s` is the DECODED content of a JSON string, not a JSON document: passing a
+/// serialized root (`[..]`, `"..."`, `null`) fails the grammar and is rejected.
 pub fn validate_numeric_string(s: &[u8]) -> Result<(), TransitionError> {
     if s.is_empty() {
         return Err(TransitionError::InvalidSerialization);
@@ -464,13 +591,112 @@ pub fn validate_numeric_string(s: &[u8]) -> Result<(), TransitionError> {
     }
     // Remaining bytes must be digits.
     for &b in &s[1..] {
-        if !matches!(b, b'0'..=b'9') {
+        if !b.is_ascii_digit() {
             return Err(TransitionError::InvalidSerialization);
         }
     }
     Ok(())
 }
 
+/// `validate_numeric_string`, additionally requiring the value to fit in `u128`.
+///
+/// Grammar violations return `InvalidSerialization`; a well-formed string whose
+/// magnitude exceeds `u128::MAX` returns `MathOverflow` — the same error a
+/// later parse would produce, so a string that passes here will parse.
+pub fn validate_numeric_string_u128(s: &[u8]) -> Result<(), TransitionError> {
+    validate_numeric_string(s)?;
+    let mut value: u128 = 0;
+    for &b in s {
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((b - b'0') as u128))
+            .ok_or(TransitionError::MathOverflow)?;
+    }
+    Ok(())
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Nested schema validation with error paths
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Expected shape of a canonical document, for `validate_with_path`.
+///
+/// Build schemas as `const` items; nested parts are `'static` references.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Schema<'a> {
+    /// A string that passes `validate_numeric_string_u128`.
+    Numeric,
+    /// Any string.
+    Str,
+    /// An array whose items all match the inner schema.
+    Array(&'a Schema<'a>),
+    /// An object with exactly these keys, each matching its schema.
+    Object(&'a [(&'a str, Schema<'a>)]),
+}
+
+/// Validate `canonical` against a nested `schema`, reporting where it failed.
+///
+/// On failure returns the error together with a JSON Pointer (RFC 6901) to the
+/// offending value, e.g. `/items/3/magnitude`. Keys are `[a-z0-9_]` only, so
+/// no pointer escaping is ever needed. The empty pointer `""` is the root; a
+/// document `parse` rejects is reported there.
+///
+/// Errors are those of the plain validators: `InvalidSerialization` for an
+/// unknown key, a missing key (pointer names the missing key), a wrong value
+/// type, or a bad numeric grammar; `MathOverflow` for a numeric string above
+/// `u128::MAX`. Violations are found in canonical (sorted-key) order, unknown
+/// keys of an object before its missing keys.
+///
+/// A developer tool for host integrators; `validate_schema` and the numeric
+/// validators keep their own signatures and bare errors.
+pub fn validate_with_path(canonical: &[u8], schema: &Schema) -> Result<(), (TransitionError, String)> {
+    let value = parse(canonical).map_err(|e| (e, String::new()))?;
+    let mut path = String::new();
+    check_at(&value, schema, &mut path).map_err(|e| (e, path))
+}
+
+/// Recursive step of `validate_with_path`. On error `path` is left pointing at
+/// the violation; on success it is restored. Depth is bounded by `MAX_DEPTH`.
+fn check_at(value: &Value, schema: &Schema, path: &mut String) -> Result<(), TransitionError> {
+    match (schema, value) {
+        (Schema::Numeric, Value::Str(s)) => validate_numeric_string_u128(s),
+        (Schema::Str, Value::Str(_)) => Ok(()),
+        (Schema::Array(item), Value::Array(items)) => {
+            for (i, v) in items.iter().enumerate() {
+                let len = path.len();
+                path.push_str(&format!("/{}", i));
+                check_at(v, item, path)?;
+                path.truncate(len);
+            }
+            Ok(())
+        }
+        (Schema::Object(fields), Value::Object(pairs)) => {
+            let len = path.len();
+            for (key, v) in pairs {
+                // Parsed keys are validated ASCII.
+                let key = std::str::from_utf8(key).map_err(|_| TransitionError::InvalidSerialization)?;
+                path.push('/');
+                path.push_str(key);
+                let field = fields.iter().find(|(name, _)| *name == key);
+                match field {
+                    Some((_, field_schema)) => check_at(v, field_schema, path)?,
+                    None => return Err(TransitionError::InvalidSerialization),
+                }
+                path.truncate(len);
+            }
+            for (name, _) in fields.iter() {
+                if value.get(name).is_none() {
+                    path.push('/');
+                    path.push_str(name);
+                    return Err(TransitionError::InvalidSerialization);
+                }
+            }
+            Ok(())
+        }
+        _ => Err(TransitionError::InvalidSerialization),
+    }
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // Tests
 // ──────────────────────────────────────────────────────────────────────────────
@@ -573,6 +799,18 @@ mod tests {
         assert_eq!(canonicalize(input), Err(TransitionError::DuplicateKey));
     }
 
+    #[test]
+    fn escaped_equivalent_duplicate_key_is_rejected() {
+        // "\u0061" decodes to "a": keys are compared AFTER decoding.
+        let input = br#"{"a":"1","\u0061":"2"}"#;
+        assert_eq!(canonicalize(input), Err(TransitionError::DuplicateKey));
+    }
+
+    #[test]
+    fn escaped_key_canonicalizes_to_decoded_form() {
+        assert_eq!(canonicalize(br#"{"\u0061":"1"}"#).unwrap(), br#"{"a":"1"}"#);
+    }
+
     // ── Constitutional vector: number literal rejection ───────────────────────
 
     #[test]
@@ -629,6 +867,82 @@ mod tests {
         assert!(validate_schema(&canonical, &["a", "b"]).is_ok());
     }
 
+    #[test]
+    fn schema_rejects_non_object_roots() {
+        for root in [&b"[]"[..], br#"["a"]"#, br#""a""#, b"null", b"true"] {
+            assert_eq!(
+                validate_schema(root, &["a"]),
+                Err(TransitionError::InvalidSerialization),
+                "non-object root {:?} must be rejected", root
+            );
+            assert_eq!(validate_schema(root, &[]), Err(TransitionError::InvalidSerialization));
+        }
+    }
+
+    #[test]
+    fn schema_rejects_trailing_content_after_object() {
+        assert_eq!(
+            validate_schema(br#"{"a":"1"} {"b":"2"}"#, &["a"]),
+            Err(TransitionError::InvalidSerialization)
+        );
+    }
+
+    /// `{"batch": {"items": [{"id", "magnitude"}...]}, "epoch"}`
+    const PAYLOAD: Schema = Schema::Object(&[
+        ("batch", Schema::Object(&[
+            ("items", Schema::Array(&Schema::Object(&[
+                ("id", Schema::Str),
+                ("magnitude", Schema::Numeric),
+            ]))),
+        ])),
+        ("epoch", Schema::Numeric),
+    ]);
+
+    fn payload(items: &str) -> Vec<u8> {
+        canonicalize(format!(r#"{{"epoch":"7","batch":{{"items":[{}]}}}}"#, items).as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn path_names_nested_numeric_violation() {
+        let good = r#"{"id":"a","magnitude":"1"}"#;
+        assert_eq!(validate_with_path(&payload(&[good; 3].join(",")), &PAYLOAD), Ok(()));
+
+        let items = [good, good, good, r#"{"id":"d","magnitude":"01"}"#].join(",");
+        assert_eq!(
+            validate_with_path(&payload(&items), &PAYLOAD),
+            Err((TransitionError::InvalidSerialization, "/batch/items/3/magnitude".to_string()))
+        );
+        let too_big = r#"{"id":"a","magnitude":"340282366920938463463374607431768211456"}"#;
+        assert_eq!(
+            validate_with_path(&payload(too_big), &PAYLOAD),
+            Err((TransitionError::MathOverflow, "/batch/items/0/magnitude".to_string()))
+        );
+    }
+
+    #[test]
+    fn path_names_nested_unknown_and_missing_keys() {
+        let items = r#"{"id":"a","magnitude":"1"},{"id":"b","magnitude":"2","rogue":"x"}"#;
+        assert_eq!(
+            validate_with_path(&payload(items), &PAYLOAD),
+            Err((TransitionError::InvalidSerialization, "/batch/items/1/rogue".to_string()))
+        );
+        assert_eq!(
+            validate_with_path(&payload(r#"{"id":"a"}"#), &PAYLOAD),
+            Err((TransitionError::InvalidSerialization, "/batch/items/0/magnitude".to_string()))
+        );
+        // Wrong type at the root, and an unparseable document, report the root.
+        assert_eq!(validate_with_path(b"[]", &PAYLOAD), Err((TransitionError::InvalidSerialization, String::new())));
+        assert_eq!(validate_with_path(b"{\"a\":1}", &PAYLOAD), Err((TransitionError::InvalidSerialization, String::new())));
+    }
+
+    #[test]
+    fn numeric_validators_reject_serialized_roots() {
+        for root in [&b"[]"[..], br#""1""#, b"null"] {
+            assert_eq!(validate_numeric_string(root), Err(TransitionError::InvalidSerialization));
+            assert_eq!(validate_numeric_string_u128(root), Err(TransitionError::InvalidSerialization));
+        }
+    }
+
     // ── Key format enforcement ────────────────────────────────────────────────
 
     #[test]
@@ -683,6 +997,34 @@ mod tests {
         assert_eq!(validate_numeric_string(b""), Err(TransitionError::InvalidSerialization));
     }
 
+    #[test]
+    fn numeric_u128_accepts_u128_max() {
+        assert!(validate_numeric_string_u128(b"340282366920938463463374607431768211455").is_ok());
+    }
+
+    #[test]
+    fn numeric_u128_rejects_u128_max_plus_one() {
+        assert_eq!(
+            validate_numeric_string_u128(b"340282366920938463463374607431768211456"),
+            Err(TransitionError::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn numeric_u128_rejects_forty_digits() {
+        assert_eq!(
+            validate_numeric_string_u128(b"1000000000000000000000000000000000000000"),
+            Err(TransitionError::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn numeric_u128_accepts_normal_value_and_keeps_grammar() {
+        assert!(validate_numeric_string_u128(b"1000000000000").is_ok());
+        assert!(validate_numeric_string_u128(b"0").is_ok());
+        assert_eq!(validate_numeric_string_u128(b"01"), Err(TransitionError::InvalidSerialization));
+    }
+
     // ── DOS bounding ──────────────────────────────────────────────────────────
 
     #[test]
@@ -693,9 +1035,7 @@ mod tests {
             s.extend_from_slice(br#"{"a":"#);
         }
         s.extend_from_slice(b"\"v\"");
-        for _ in 0..33 {
-            s.push(b'}');
-        }
+        s.extend(std::iter::repeat_n(b'}', 33));
         assert_eq!(canonicalize(&s), Err(TransitionError::InvalidSerialization));
     }
 
@@ -707,12 +1047,80 @@ mod tests {
             s.extend_from_slice(br#"{"a":"#);
         }
         s.extend_from_slice(b"\"v\"");
-        for _ in 0..31 {
-            s.push(b'}');
-        }
+        s.extend(std::iter::repeat_n(b'}', 31));
         assert!(canonicalize(&s).is_ok());
     }
 
+    /// `depth` containers alternating object/array, innermost holding `"v"`.
+    fn mixed_nesting(depth: usize) -> Vec<u8> {
+        let mut s: Vec<u8> = Vec::new();
+        for i in 0..depth {
+            s.extend_from_slice(if i % 2 == 0 { br#"{"a":"# } else { b"[" });
+        }
+        s.extend_from_slice(b"\"v\"");
+        for i in (0..depth).rev() {
+            s.push(if i % 2 == 0 { b'}' } else { b']' });
+        }
+        s
+    }
+
+    #[test]
+    fn mixed_nesting_at_exactly_max_depth() {
+        assert!(canonicalize(&mixed_nesting(MAX_DEPTH)).is_ok());
+        assert_eq!(canonicalize(&mixed_nesting(MAX_DEPTH + 1)), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn pathological_nesting_is_rejected_without_deep_recursion() {
+        // Nearly MAX_INPUT_BYTES of unclosed brackets. The depth guard fires at
+        // container MAX_DEPTH + 1, so this returns an error instead of
+        // recursing tens of thousands of frames deep.
+        let brackets = vec![b'['; MAX_INPUT_BYTES];
+        assert_eq!(canonicalize(&brackets), Err(TransitionError::InvalidSerialization));
+        let objects = br#"{"a":"#.repeat(MAX_INPUT_BYTES / 5);
+        assert_eq!(canonicalize(&objects), Err(TransitionError::InvalidSerialization));
+    }
+
+    /// Root array holding a 30-deep chain of arrays, then groups of up to
+    /// 1 023 empty arrays, totalling exactly `total` values.
+    fn doc_with_nodes(total: usize) -> Vec<u8> {
+        let chain = 30;
+        let mut doc = Vec::new();
+        doc.push(b'[');
+        doc.extend(core::iter::repeat_n(b'[', chain));
+        doc.extend(core::iter::repeat_n(b']', chain));
+        let mut remaining = total - 1 - chain;
+        while remaining > 0 {
+            doc.extend_from_slice(b",[");
+            remaining -= 1;
+            let take = remaining.min(MAX_ARRAY_ITEMS - 1);
+            for i in 0..take {
+                if i > 0 {
+                    doc.push(b',');
+                }
+                doc.extend_from_slice(b"[]");
+            }
+            remaining -= take;
+            doc.push(b']');
+        }
+        doc.push(b']');
+        doc
+    }
+
+    #[test]
+    fn wide_and_deep_document_at_node_cap_is_accepted() {
+        let doc = doc_with_nodes(MAX_TOTAL_NODES);
+        assert!(doc.len() <= MAX_INPUT_BYTES);
+        assert_eq!(canonicalize(&doc).unwrap(), doc);
+    }
+
+    #[test]
+    fn document_one_node_over_cap_is_rejected() {
+        let doc = doc_with_nodes(MAX_TOTAL_NODES + 1);
+        assert!(doc.len() <= MAX_INPUT_BYTES, "must fail on nodes, not bytes");
+        assert_eq!(canonicalize(&doc), Err(TransitionError::InvalidSerialization));
+    }
+
     // ── String escaping ───────────────────────────────────────────────────────
 
     #[test]
@@ -729,6 +1137,14 @@ mod tests {
         assert_eq!(canonical, br#""hello\nworld""#);
     }
 
+    #[test]
+    fn forward_slash_is_emitted_unescaped() {
+        // RFC 8785 never escapes '/': "\/" decodes to '/' and is emitted raw.
+        assert_eq!(canonicalize(br#""a\/b""#).unwrap(), br#""a/b""#);
+        assert_eq!(canonicalize(br#""a/b""#).unwrap(), br#""a/b""#);
+        assert_eq!(canonicalize(br#"{"path":"\/x\/y"}"#).unwrap(), br#"{"path":"/x/y"}"#);
+    }
+
     #[test]
     fn trailing_content_is_rejected() {
         assert_eq!(canonicalize(b"{}{}"), Err(TransitionError::InvalidSerialization));
@@ -761,4 +1177,64 @@ mod tests {
         let canonical = canonicalize(input).unwrap();
         assert_eq!(canonical, br#"{"items":["b","a","c"]}"#);
     }
+
+    // ── Sized output ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn sized_output_never_ends_in_whitespace() {
+        let inputs: [&[u8]; 8] = [
+            b"{}\n",
+            b"  [ ]  \r\n",
+            b"null\t",
+            b"true ",
+            b"false\n\n",
+            b"\"x\"\n",
+            br#" { "b" : [ "1" , { } ] , "a" : null } "#,
+            br#"["a",true,false,null]"#,
+        ];
+        for input in inputs {
+            let (bytes, len) = canonicalize_sized(input).unwrap();
+            assert_eq!(len, bytes.len());
+            let last = *bytes.last().unwrap();
+            assert!(
+                matches!(last, b'}' | b']' | b'"' | b'l' | b'e'),
+                "unexpected trailing byte {:#04x} for input {:?}", last, input
+            );
+        }
+    }
+
+    #[test]
+    fn sized_output_propagates_errors() {
+        assert_eq!(canonicalize_sized(b"{} x"), Err(TransitionError::InvalidSerialization));
+    }
+
+    // ── parse / CanonicalObject ───────────────────────────────────────────────
+
+    #[test]
+    fn parse_exposes_decoded_values() {
+        let v = parse(br#"{"b":["x",null],"a":"1"}"#).unwrap();
+        assert_eq!(v.get("a").and_then(Value::as_str_bytes), Some(&b"1"[..]));
+        assert_eq!(v.get("b").and_then(Value::as_array).map(|a| a.len()), Some(2));
+        assert_eq!(v.get("missing"), None);
+        assert_eq!(parse(b"{} x"), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn canonical_object_sorts_and_nests() {
+        let inner = CanonicalObject::new().string("z", b"2").string("y", b"1").into_value();
+        let out = CanonicalObject::new()
+            .value("outer", inner)
+            .string("amount", b"5")
+            .finish()
+            .unwrap();
+        assert_eq!(out, br#"{"amount":"5","outer":{"y":"1","z":"2"}}"#);
+    }
+
+    #[test]
+    fn canonical_object_rejects_duplicate_and_bad_keys() {
+        let dup = CanonicalObject::new().string("a", b"1").string("a", b"2");
+        assert_eq!(dup.finish(), Err(TransitionError::DuplicateKey));
+        let bad = CanonicalObject::new().string("Bad", b"1");
+        assert_eq!(bad.finish(), Err(TransitionError::InvalidSerialization));
+    }
 }
@@ -159,4 +159,30 @@ mod tests {
             "invalid pubkey must fail"
         );
     }
+
+    // Small-order key: accepted by plain `verify`, rejected by `verify_strict`.
+    #[test]
+    fn small_order_key_forgery_is_rejected_only_by_strict_verification() {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        // A = identity point (y = 1), R = identity, s = 0. The unhardened check
+        // [s]B == R + [k]A reduces to O == O, so this "signature" is valid for
+        // EVERY message under non-strict verification.
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let mut forged = [0u8; 64];
+        forged[..32].copy_from_slice(&identity);
+
+        let vk = VerifyingKey::from_bytes(&identity).unwrap();
+        assert!(vk.is_weak());
+        let sig = Signature::from_bytes(&forged);
+        for message in [&b""[..], b"transfer everything", b"epoch 7"] {
+            assert!(vk.verify(message, &sig).is_ok(), "non-strict verify accepts the forgery");
+            assert_eq!(
+                verify(&identity, message, &forged),
+                Err(TransitionError::InvalidSignature),
+                "the kernel must reject the small-order forgery"
+            );
+        }
+    }
 }
@@ -16,6 +16,8 @@
 //! These prefix bytes prevent second-preimage attacks on the Merkle tree.
 //! See: RFC 6962 §2.1 and the Merkle tree specification.
 
+use crate::TransitionError;
+
 /// A SHA-256 digest: 32 bytes.
 pub type Digest = [u8; 32];
 
@@ -25,6 +27,12 @@ pub const LEAF_PREFIX: u8 = 0x00;
 /// The domain separation prefix for Merkle internal node hashes.
 pub const NODE_PREFIX: u8 = 0x01;
 
+/// Longest message SHA-256 can hash: FIPS 180-4 encodes the length as a
+/// 64-bit count of bits, so at most `u64::MAX / 8` bytes (2^61 - 1, ~2 EiB).
+/// Unreachable for kernel inputs (64 KiB canonical cap) and for any slice on a
+/// 32-bit target.
+pub const SHA256_MAX_INPUT_BYTES: u64 = u64::MAX / 8;
+
 // ──────────────────────────────────────────────────────────────────────────────
 // FIPS 180-4 §4.2.2 — SHA-256 initial hash values
 // (First 32 bits of the fractional parts of the square roots of the first 8 primes)
@@ -155,40 +163,153 @@ fn feed_byte(state: &mut [u32; 8], pending: &mut [u8; 64], pending_len: &mut usi
     }
 }
 
+/// Streaming SHA-256 hasher. Feeding the same bytes in any split produces the
+/// same digest as a single `sha256` call over their concatenation.
+///
+/// ```text
+/// Sha256::new().update(&[NODE_PREFIX]).update_digest(&left).update_digest(&right).finalize()
+/// ```
+#[derive(Clone)]
+pub struct Sha256 {
+    state: [u32; 8],
+    pending: [u8; 64],
+    pending_len: usize,
+    /// Total bytes fed so far (FIPS 180-4 limits messages to < 2^64 bits).
+    byte_len: u64,
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256 {
+    /// A fresh hasher in the FIPS 180-4 initial state.
+    pub fn new() -> Self {
+        Sha256 { state: H, pending: [0u8; 64], pending_len: 0, byte_len: 0 }
+    }
+
+    /// Feed `input` into the hasher.
+    pub fn update(&mut self, input: &[u8]) -> &mut Self {
+        for &byte in input {
+            feed_byte(&mut self.state, &mut self.pending, &mut self.pending_len, byte);
+        }
+        // Saturating: any total past SHA256_MAX_INPUT_BYTES is already rejected
+        // by `try_finalize`, so pinning at u64::MAX cannot wrap back into range.
+        self.byte_len = self.byte_len.saturating_add(input.len() as u64);
+        self
+    }
+
+    /// Feed a whole 32-byte digest. Typed form of `update(&d[..])`: a digest
+    /// can only be fed in full, never as a partial slice.
+    pub fn update_digest(&mut self, d: &Digest) -> &mut Self {
+        self.update(&d[..])
+    }
+
+    /// Apply FIPS 180-4 §5.1.1 padding and return the digest.
+    ///
+    /// Takes `&self`, not `self` as first specified: `update` returns
+    /// `&mut Self`, so a fluent chain ending in `finalize()` only compiles if
+    /// finalizing borrows. It pads a copy of the state, so the hasher is left
+    /// unchanged and can keep absorbing (as `CheckpointHasher` relies on).
+    ///
+    /// Never panics. Past `SHA256_MAX_INPUT_BYTES` the length field saturates
+    /// at `u64::MAX` bits instead of wrapping; use `try_finalize` to reject
+    /// such input instead.
+    pub fn finalize(&self) -> Digest {
+        self.pad(self.byte_len.saturating_mul(8))
+    }
+
+    /// `finalize`, returning `HashInputTooLong` once more than
+    /// `SHA256_MAX_INPUT_BYTES` were fed.
+    pub fn try_finalize(&self) -> Result<Digest, TransitionError> {
+        let bit_len: u64 = self.byte_len.checked_mul(8).ok_or(TransitionError::HashInputTooLong)?;
+        Ok(self.pad(bit_len))
+    }
+
+    /// Pad a copy of the state with the given 64-bit length field.
+    fn pad(&self, bit_len: u64) -> Digest {
+        let mut state = self.state;
+        let mut pending = self.pending;
+        let mut pending_len = self.pending_len;
+
+        // FIPS 180-4 §5.1.1 — append the single bit '1' (as 0x80 byte).
+        feed_byte(&mut state, &mut pending, &mut pending_len, 0x80);
+
+        // Pad with zero bytes until pending_len == 56 (so length fits in last 8 bytes).
+        while pending_len != 56 {
+            feed_byte(&mut state, &mut pending, &mut pending_len, 0x00);
+        }
+
+        // Append the original message length as a 64-bit big-endian integer.
+        for byte in bit_len.to_be_bytes() {
+            feed_byte(&mut state, &mut pending, &mut pending_len, byte);
+        }
+
+        // Produce the 256-bit (32-byte) digest.
+        let mut digest = [0u8; 32];
+        for (i, word) in state.iter().enumerate() {
+            digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+}
+
 /// Compute SHA-256 over an arbitrary byte slice.
 /// This is the canonical hash function for all Civilisation OS kernel operations.
 /// Implements FIPS 180-4 §5.1.1 (padding) and §6.2.2 (hash computation).
+///
+/// Inputs longer than `SHA256_MAX_INPUT_BYTES`, which no 32-bit target and no
+/// kernel-bounded input can reach, get a saturated length field (see `finalize`).
 pub fn sha256(input: &[u8]) -> Digest {
-    let mut state = H;
-    let bit_len: u64 = (input.len() as u64).wrapping_mul(8);
+    Sha256::new().update(input).finalize()
+}
 
-    let mut pending = [0u8; 64];
-    let mut pending_len: usize = 0;
+/// `SHA256(SHA256(input))`, for external anchoring schemes (Bitcoin-style
+/// commitments) that expect it. INTEROP ONLY: no domain prefix, so it is not a
+/// Merkle hash and must never stand in for `hash_leaf` / `hash_node`.
+pub fn double_sha256(input: &[u8]) -> Digest {
+    sha256(&sha256(input))
+}
 
-    // Feed all input bytes.
-    for &byte in input {
-        feed_byte(&mut state, &mut pending, &mut pending_len, byte);
+/// Constant-time digest equality: XOR every byte pair, OR the results, no early exit.
+/// Use where an attacker could time a comparison against a secret-derived digest.
+pub fn ct_eq(a: &Digest, b: &Digest) -> bool {
+    let mut diff = 0u8;
+    for i in 0..32 {
+        diff |= a[i] ^ b[i];
     }
+    diff == 0
+}
 
-    // FIPS 180-4 §5.1.1 — append the single bit '1' (as 0x80 byte).
-    feed_byte(&mut state, &mut pending, &mut pending_len, 0x80);
-
-    // Pad with zero bytes until pending_len == 56 (so length fits in last 8 bytes).
-    while pending_len != 56 {
-        feed_byte(&mut state, &mut pending, &mut pending_len, 0x00);
+/// Lowercase hex encoding, two characters per byte. The one hex encoder in the
+/// kernel: audit JSON, display IDs and tooling serde all go through it.
+pub fn to_hex(bytes: &[u8]) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for &b in bytes {
+        out.push(HEX[(b >> 4) as usize] as char);
+        out.push(HEX[(b & 0xF) as usize] as char);
     }
+    out
+}
 
-    // Append the original message length as a 64-bit big-endian integer.
-    for byte in bit_len.to_be_bytes() {
-        feed_byte(&mut state, &mut pending, &mut pending_len, byte);
+/// Inverse of `to_hex`: decode an even number of lowercase hex characters.
+/// Returns `InvalidSerialization` for an odd length or any other character,
+/// so every byte string has exactly one accepted encoding.
+pub fn from_hex(hex: &[u8]) -> Result<Vec<u8>, TransitionError> {
+    fn nibble(c: u8) -> Result<u8, TransitionError> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            _ => Err(TransitionError::InvalidSerialization),
+        }
     }
-
-    // Produce the 256-bit (32-byte) digest.
-    let mut digest = [0u8; 32];
-    for (i, word) in state.iter().enumerate() {
-        digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    if !hex.len().is_multiple_of(2) {
+        return Err(TransitionError::InvalidSerialization);
     }
-    digest
+    hex.chunks_exact(2).map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?)).collect()
 }
 
 /// Hash a Merkle leaf: SHA256(0x00 || leaf_bytes)
@@ -199,6 +320,22 @@ pub fn hash_leaf(leaf_bytes: &[u8]) -> Digest {
     sha256(&input)
 }
 
+/// Hash a batch of Merkle leaves: element `i` is exactly `hash_leaf(&leaves[i])`.
+///
+/// Allocates only the output vector. The `0x00` prefix is absorbed once into a
+/// template hasher that each leaf starts from, and every iteration is independent
+/// of the others, so the loop can later be vectorized without changing results.
+pub fn hash_leaves(leaves: &[Vec<u8>]) -> Vec<Digest> {
+    let mut prefixed = Sha256::new();
+    prefixed.update(&[LEAF_PREFIX]);
+
+    let mut out = Vec::with_capacity(leaves.len());
+    for leaf in leaves {
+        out.push(prefixed.clone().update(leaf).finalize());
+    }
+    out
+}
+
 /// Hash a Merkle internal node: SHA256(0x01 || left_hash || right_hash)
 pub fn hash_node(left: &Digest, right: &Digest) -> Digest {
     let mut input = Vec::with_capacity(1 + 32 + 32);
@@ -208,6 +345,42 @@ pub fn hash_node(left: &Digest, right: &Digest) -> Digest {
     sha256(&input)
 }
 
+/// Rolling SHA-256 over a chain of epoch states, for light-client sync checkpoints.
+///
+/// Each `absorb` feeds `be8(epoch_number) || state_root` into one streaming
+/// hasher, so memory is constant however long the chain is. `checkpoint` is the
+/// digest of everything absorbed so far; it depends on every state and on their
+/// order. A host absorbs every epoch and publishes a checkpoint every N epochs.
+/// It takes the pair rather than an `EpochState` so physics stays below state.
+#[derive(Clone, Default)]
+pub struct CheckpointHasher {
+    hasher: Sha256,
+    absorbed: u64,
+}
+
+impl CheckpointHasher {
+    /// An empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorb the next state in the chain, given its epoch number and state root.
+    pub fn absorb(&mut self, epoch_number: u64, state_root: &Digest) {
+        self.hasher.update(&epoch_number.to_be_bytes()).update_digest(state_root);
+        self.absorbed += 1;
+    }
+
+    /// Number of states absorbed so far.
+    pub fn absorbed(&self) -> u64 {
+        self.absorbed
+    }
+
+    /// Digest over every state absorbed so far. Does not reset the accumulator.
+    pub fn checkpoint(&self) -> Digest {
+        self.hasher.finalize()
+    }
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // Constitutional test vectors from NIST FIPS 180-4 and NIST CAVP.
 // These are byte-exact pinned values. Any deviation is a constitutional crisis.
@@ -225,6 +398,20 @@ mod tests {
         out
     }
 
+    #[test]
+    fn hash_leaves_matches_per_leaf_hash() {
+        // Lengths 0..=199 cover every padding boundary (55/56/63/64 after the prefix).
+        let leaves: Vec<Vec<u8>> = (0..1000u32)
+            .map(|i| (0..i % 200).map(|j| (i.wrapping_mul(31) ^ j) as u8).collect())
+            .collect();
+        let batch = hash_leaves(&leaves);
+        assert_eq!(batch.len(), leaves.len());
+        for (leaf, digest) in leaves.iter().zip(&batch) {
+            assert_eq!(*digest, hash_leaf(leaf));
+        }
+        assert!(hash_leaves(&[]).is_empty());
+    }
+
     #[test]
     fn raw_input_bytes_abc() {
         // b"abc" must be exactly ASCII 0x61, 0x62, 0x63. No encoding surprises.
@@ -259,6 +446,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn padding_boundary_vectors() {
+        // SHA-256(b"a" * n), cross-checked against Python's hashlib (OpenSSL).
+        // 55: padding and length fit one block. 56, 63: the length field spills
+        // into a second block. 64: exactly one full block. 119/120: the same
+        // spill one block later. 128: exactly two full blocks.
+        let vectors = [
+            ( 55, "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"),
+            ( 56, "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a"),
+            ( 63, "7d3e74a05d7db15bce4ad9ec0658ea98e3f06eeecf16b4c6fff2da457ddc2f34"),
+            ( 64, "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb"),
+            (119, "31eba51c313a5c08226adf18d4a359cfdfd8d2e816b13f4af952f7ea6584dcfb"),
+            (120, "2f3d335432c70b580af0e8e1b3674a7c020d683aa5f73aaaedfdc55af904c21c"),
+            (128, "6836cf13bac400e9105071cd6af47084dfacad4e5e302c94bfed24e013afb73e"),
+        ];
+        for (len, expected) in vectors {
+            assert_eq!(sha256(&vec![b'a'; len]), nist(expected), "SHA-256 of {} bytes", len);
+        }
+    }
+
     #[test]
     fn domain_separation_differs() {
         let leaf_h = hash_leaf(b"test");
@@ -273,4 +480,148 @@ mod tests {
         let d = sha256(b"x");
         assert_eq!(hash_node(&d, &d), hash_node(&d, &d));
     }
+
+    #[test]
+    fn hash_node_is_not_commutative() {
+        // NodePosition semantics rest on this: swapping children changes the parent.
+        let x = hash_leaf(b"left");
+        let y = hash_leaf(b"right");
+        assert_ne!(hash_node(&x, &y), hash_node(&y, &x));
+    }
+
+    #[test]
+    fn hash_node_of_duplicated_child_is_stable() {
+        // The padding-duplication case: SHA256(0x01 || x || x).
+        let x = hash_leaf(b"last");
+        let mut buf = vec![NODE_PREFIX];
+        buf.extend_from_slice(&x);
+        buf.extend_from_slice(&x);
+        assert_eq!(hash_node(&x, &x), sha256(&buf));
+        assert_ne!(hash_node(&x, &x), x, "a duplicated node must not collapse to its child");
+    }
+
+    #[test]
+    fn fluent_node_hash_matches_hash_node() {
+        let pairs = [
+            (sha256(b""), sha256(b"abc")),
+            (hash_leaf(b"left"), hash_leaf(b"right")),
+            (hash_leaf(b"last"), hash_leaf(b"last")),
+        ];
+        for (left, right) in pairs {
+            let fluent = Sha256::new()
+                .update(&[NODE_PREFIX])
+                .update_digest(&left)
+                .update_digest(&right)
+                .finalize();
+            assert_eq!(fluent, hash_node(&left, &right));
+        }
+    }
+
+    #[test]
+    fn streaming_split_matches_one_shot() {
+        // 448-bit NIST message fed across the 55/56/64-byte padding boundaries.
+        let msg = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        for split in [0, 1, 31, 55, 56] {
+            let (a, b) = msg.split_at(split);
+            assert_eq!(Sha256::new().update(a).update(b).finalize(), sha256(msg));
+        }
+        let long = [0x61u8; 200];
+        assert_eq!(Sha256::new().update(&long[..64]).update(&long[64..]).finalize(), sha256(&long));
+    }
+
+    #[test]
+    fn abc_in_three_updates_matches_nist() {
+        let digest = Sha256::new().update(b"a").update(b"b").update(b"c").finalize();
+        assert_eq!(digest, nist("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"));
+    }
+
+    #[test]
+    fn length_field_is_checked_at_the_fips_limit() {
+        // 2^61 - 1 bytes is the last length whose bit count fits in 64 bits.
+        assert_eq!(SHA256_MAX_INPUT_BYTES, (1 << 61) - 1);
+        assert_eq!(SHA256_MAX_INPUT_BYTES.checked_mul(8), Some(u64::MAX - 7));
+
+        // Such inputs cannot be allocated, so set the byte counter directly.
+        let at_limit = Sha256 { byte_len: SHA256_MAX_INPUT_BYTES, ..Sha256::new() };
+        assert!(at_limit.try_finalize().is_ok());
+        let over = Sha256 { byte_len: SHA256_MAX_INPUT_BYTES + 1, ..Sha256::new() };
+        assert_eq!(over.try_finalize(), Err(TransitionError::HashInputTooLong));
+        // `finalize` saturates the length field instead of panicking or wrapping.
+        assert_ne!(over.finalize(), at_limit.finalize());
+        let wrapped = Sha256 { byte_len: 0, ..Sha256::new() };
+        assert_ne!(over.finalize(), wrapped.finalize());
+
+        // The counter saturates rather than wrapping back under the limit.
+        let mut h = Sha256 { byte_len: u64::MAX - 1, ..Sha256::new() };
+        h.update(b"abc");
+        assert_eq!(h.byte_len, u64::MAX);
+        assert_eq!(h.try_finalize(), Err(TransitionError::HashInputTooLong));
+
+        assert_eq!(Sha256::new().update(b"abc").try_finalize(), Ok(sha256(b"abc")));
+    }
+
+    #[test]
+    fn ct_eq_agrees_with_eq_on_random_pairs() {
+        let mut a = sha256(b"ct_eq seed");
+        for i in 0..256u32 {
+            let b = sha256(&a);
+            assert_eq!(ct_eq(&a, &b), a == b);
+            assert!(ct_eq(&a, &a));
+            // Every single-bit difference, across all 32 bytes, is detected.
+            let mut flipped = a;
+            flipped[(i / 8) as usize] ^= 1 << (i % 8);
+            assert!(!ct_eq(&a, &flipped), "bit {} undetected", i);
+            a = b;
+        }
+    }
+
+    #[test]
+    fn double_sha256_vectors() {
+        assert_eq!(double_sha256(b"hello"), nist("9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50"));
+        assert_eq!(double_sha256(b""), nist("5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456"));
+        assert_ne!(double_sha256(b"x"), hash_leaf(&sha256(b"x")));
+    }
+
+    #[test]
+    fn to_hex_is_lowercase_and_byte_ordered() {
+        assert_eq!(to_hex(&[]), "");
+        assert_eq!(to_hex(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+    }
+
+    #[test]
+    fn from_hex_inverts_to_hex_and_accepts_nothing_else() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(from_hex(to_hex(&bytes).as_bytes()).unwrap(), bytes);
+        assert_eq!(from_hex(b"").unwrap(), Vec::<u8>::new());
+        for bad in [&b"abc"[..], b"0g", b"AB", b" 0"] {
+            assert_eq!(from_hex(bad), Err(TransitionError::InvalidSerialization), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn checkpoints_are_stable_and_order_dependent() {
+        let chain: Vec<(u64, Digest)> =
+            (0..200u64).map(|n| (n, sha256(&n.to_be_bytes()))).collect();
+        let run = |states: &[(u64, Digest)]| {
+            let mut h = CheckpointHasher::new();
+            let mut checkpoints = Vec::new();
+            for (epoch_number, state_root) in states {
+                h.absorb(*epoch_number, state_root);
+                if h.absorbed().is_multiple_of(50) {
+                    checkpoints.push(h.checkpoint());
+                }
+            }
+            checkpoints
+        };
+
+        let first = run(&chain);
+        assert_eq!(first.len(), 4);
+        assert_eq!(run(&chain), first);
+        assert!(first.windows(2).all(|w| w[0] != w[1]));
+
+        // Swapping two states inside the first window changes every checkpoint.
+        let mut swapped = chain.clone();
+        swapped.swap(10, 11);
+        assert!(run(&swapped).iter().zip(&first).all(|(a, b)| a != b));
+    }
 }
@@ -6,10 +6,14 @@
 //! 3. Depth Padding:     If leaf_count > 0, pad to next power of two by duplicating the final node.
 //! 4. MAX_MERKLE_DEPTH:  40 levels. Input exceeding 2^40 leaves is a TransitionError.
 //! 5. Domain Separation: leaf_hash = SHA256(0x00 || leaf), node_hash = SHA256(0x01 || L || R).
+//! 6. Non-Empty Leaves:  An empty leaf is rejected (its hash IS the empty-tree root).
+//!
+//! `prove_consistency` / `verify_consistency` show that one root is an
+//! append-only extension of another, for light-client sync of growing pools.
 
 use std::vec::Vec;
 use crate::TransitionError;
-use crate::physics::hashing::{Digest, hash_leaf, hash_node, sha256, LEAF_PREFIX};
+use crate::physics::hashing::{ct_eq, Digest, hash_leaves, hash_node, sha256, LEAF_PREFIX};
 
 /// Maximum allowed Merkle tree depth. Supports up to 2^40 ≈ 1_099_511_627_776 leaves.
 pub const MAX_MERKLE_DEPTH: usize = 40;
@@ -26,6 +30,7 @@ pub fn empty_tree_root() -> Digest {
 /// This function does NOT sort — sorting is the caller's constitutional obligation.
 ///
 /// Returns TransitionError::PayloadLimitExceeded if leaves.len() > 2^MAX_MERKLE_DEPTH.
+/// Returns TransitionError::InvalidSerialization if any leaf is empty (rule 6).
 pub fn compute_merkle_root(leaves: &[Vec<u8>]) -> Result<Digest, TransitionError> {
     if leaves.is_empty() {
         return Ok(empty_tree_root());
@@ -38,25 +43,36 @@ pub fn compute_merkle_root(leaves: &[Vec<u8>]) -> Result<Digest, TransitionError
         return Err(TransitionError::PayloadLimitExceeded);
     }
 
+    // A pool member always carries a non-empty serialized value. Rejecting `[]`
+    // here removes the empty-tree / single-empty-leaf collision at the source.
+    if leaves.iter().any(|l| l.is_empty()) {
+        return Err(TransitionError::InvalidSerialization);
+    }
+
     // Hash all leaves with domain separation.
-    let mut nodes: Vec<Digest> = leaves.iter().map(|l| hash_leaf(l)).collect();
+    let mut nodes: Vec<Digest> = hash_leaves(leaves);
 
     // Pad to next power of two by duplicating the final node.
-    let padded_len = next_power_of_two(nodes.len());
+    // Checked: on a 32-bit target a length above 2^31 has no usize power of two.
+    let padded_len = next_power_of_two(nodes.len()).ok_or(TransitionError::PayloadLimitExceeded)?;
     while nodes.len() < padded_len {
         let last = *nodes.last().unwrap(); // safe: nodes is non-empty here
         nodes.push(last);
     }
 
     // Build the tree bottom-up until one root remains.
+    // INVARIANT: every level's length is a power of two (the padding above makes
+    // the leaf level one, and halving preserves it), so no level is odd and > 1.
     while nodes.len() > 1 {
         let mut next_level: Vec<Digest> = Vec::with_capacity(nodes.len() / 2);
         for pair in nodes.chunks_exact(2) {
             next_level.push(hash_node(&pair[0], &pair[1]));
         }
-        // If after reduction we still have an odd count (shouldn't happen after padding,
-        // but defensive duplication per spec: duplicate the final node).
-        if next_level.len() % 2 != 0 && next_level.len() > 1 {
+        // Defensive duplication per spec. Unreachable by the invariant above;
+        // reaching it would mean the padding is wrong (tests count hits).
+        if !next_level.len().is_multiple_of(2) && next_level.len() > 1 {
+            #[cfg(test)]
+            ODD_LEVEL_DUPLICATIONS.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
             let last = *next_level.last().unwrap();
             next_level.push(last);
         }
@@ -66,19 +82,140 @@ pub fn compute_merkle_root(leaves: &[Vec<u8>]) -> Result<Digest, TransitionError
     Ok(nodes[0])
 }
 
+// ──────────────────────────────────────────────────────────────────────────────
+// Consistency proofs (append-only growth)
+// ──────────────────────────────────────────────────────────────────────────────
+//
+// RFC 6962's proof shape assumes unbalanced trees; ours pads by duplicating
+// the final leaf, so an old root is generally NOT a subtree of the new one.
+// The proof instead authenticates the last old leaf, index `m - 1`, in both:
+//
+//   proof = [hash_leaf(leaf[m-1]), sibling_0, ..., sibling_{d_new - 1}]
+//
+// where the siblings are that leaf's authentication path in the new tree.
+// Every LEFT sibling on the path covers leaves below `m - 1`, so it is the
+// same node in both trees, and none occurs at or above the old depth
+// (`m - 1 < 2^d_old`). Every RIGHT sibling in the old tree covers only
+// padding, i.e. copies of leaf `m - 1`. The old root is therefore a function
+// of the proof, and recomputing both roots from the same left siblings binds
+// the new tree's first `m` leaves to the old tree's leaves.
+
+/// Depth of the padded tree over `size` leaves. `size` must be ≤ 2^MAX_MERKLE_DEPTH.
+fn padded_depth(size: usize) -> Result<usize, TransitionError> {
+    if size as u128 > 1u128 << MAX_MERKLE_DEPTH {
+        return Err(TransitionError::PayloadLimitExceeded);
+    }
+    let padded = next_power_of_two(size).ok_or(TransitionError::PayloadLimitExceeded)?;
+    Ok(padded.trailing_zeros() as usize)
+}
+
+/// Build a consistency proof that `compute_merkle_root(leaves)` extends the
+/// root of the first `old_size` leaves. See the section comment for the format.
+///
+/// `old_size == 0` yields an empty proof (everything extends the empty tree).
+/// Returns `InvalidSerialization` if `old_size > leaves.len()` or any leaf is
+/// empty, and `PayloadLimitExceeded` as `compute_merkle_root` does. Returns a
+/// `Result` rather than a bare `Vec` so bad input is never a panic.
+pub fn prove_consistency(leaves: &[Vec<u8>], old_size: usize) -> Result<Vec<Digest>, TransitionError> {
+    if old_size > leaves.len() || leaves.iter().any(|l| l.is_empty()) {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    if old_size == 0 {
+        return Ok(Vec::new());
+    }
+    let depth = padded_depth(leaves.len())?;
+
+    let mut level: Vec<Digest> = hash_leaves(leaves);
+    let last = *level.last().unwrap(); // safe: old_size ≥ 1 ≤ leaves.len()
+    level.resize(1 << depth, last);
+
+    let mut index = old_size - 1;
+    let mut proof = Vec::with_capacity(1 + depth);
+    proof.push(level[index]);
+    while level.len() > 1 {
+        proof.push(level[index ^ 1]);
+        level = level.chunks_exact(2).map(|pair| hash_node(&pair[0], &pair[1])).collect();
+        index /= 2;
+    }
+    Ok(proof)
+}
+
+/// Verify that `new_root` (over `new_size` leaves) is an append-only extension
+/// of `old_root` (over `old_size` leaves): the first `old_size` leaves agree.
+///
+/// # Errors
+///
+/// - `InvalidSerialization` — `old_size > new_size`.
+/// - `PayloadLimitExceeded` — `new_size` exceeds 2^MAX_MERKLE_DEPTH.
+/// - `InvalidMerkleWitness` — the proof has the wrong length or does not
+///   reproduce both roots (including a non-empty `old_root` for size 0).
+pub fn verify_consistency(
+    old_root: Digest,
+    old_size: usize,
+    new_root: Digest,
+    new_size: usize,
+    proof:    &[Digest],
+) -> Result<(), TransitionError> {
+    if old_size > new_size {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    let new_depth = padded_depth(new_size)?;
+    if old_size == 0 {
+        return if proof.is_empty() && old_root == empty_tree_root() {
+            Ok(())
+        } else {
+            Err(TransitionError::InvalidMerkleWitness)
+        };
+    }
+    let old_depth = padded_depth(old_size)?;
+    if proof.len() != 1 + new_depth {
+        return Err(TransitionError::InvalidMerkleWitness);
+    }
+
+    let index = old_size - 1;
+    let (mut old_node, mut new_node) = (proof[0], proof[0]);
+    let mut padding = proof[0]; // root of an all-padding subtree at this height
+    for (height, sibling) in proof[1..].iter().enumerate() {
+        if (index >> height) & 1 == 1 {
+            // Left sibling: shared by both trees (only below the old depth).
+            old_node = hash_node(sibling, &old_node);
+            new_node = hash_node(sibling, &new_node);
+        } else {
+            if height < old_depth {
+                old_node = hash_node(&old_node, &padding);
+            }
+            new_node = hash_node(&new_node, sibling);
+        }
+        padding = hash_node(&padding, &padding);
+    }
+
+    // Both checks always run: `&` rather than `||` keeps the timing flat.
+    if !(ct_eq(&old_node, &old_root) & ct_eq(&new_node, &new_root)) {
+        return Err(TransitionError::InvalidMerkleWitness);
+    }
+    Ok(())
+}
+
+/// Test-only count of times the defensive odd-level duplication ran. Must stay 0.
+#[cfg(test)]
+static ODD_LEVEL_DUPLICATIONS: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
 /// Returns the smallest power of two >= n. Returns 1 for n == 0.
-fn next_power_of_two(n: usize) -> usize {
-    if n <= 1 { return 1; }
+/// Returns `None` if that power of two does not fit in `usize` (a plain shift
+/// would wrap to zero and loop forever on 32-bit targets).
+fn next_power_of_two(n: usize) -> Option<usize> {
+    if n <= 1 { return Some(1); }
     let mut result = 1usize;
     while result < n {
-        result <<= 1;
+        result = result.checked_mul(2)?;
     }
-    result
+    Some(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::physics::hashing::hash_leaf;
 
     #[test]
     fn empty_tree_is_deterministic() {
@@ -98,6 +235,24 @@ mod tests {
         assert_eq!(root, expected);
     }
 
+    #[test]
+    fn single_empty_leaf_is_rejected() {
+        // Would otherwise equal empty_tree_root() — the zero-leaf tree.
+        assert_eq!(compute_merkle_root(&[vec![]]), Err(TransitionError::InvalidSerialization));
+        assert_eq!(compute_merkle_root(&[]).unwrap(), empty_tree_root());
+    }
+
+    #[test]
+    fn empty_leaf_among_others_is_rejected() {
+        let leaves = vec![b"a".to_vec(), vec![], b"c".to_vec()];
+        assert_eq!(compute_merkle_root(&leaves), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn single_non_empty_leaf_still_works() {
+        assert_eq!(compute_merkle_root(&[vec![0x00]]).unwrap(), hash_leaf(&[0x00]));
+    }
+
     #[test]
     fn two_leaf_tree() {
         let a = b"aaa".to_vec();
@@ -132,4 +287,106 @@ mod tests {
         // Pre-sorting is caller's responsibility. Different order → different root.
         assert_ne!(root_ab, root_ba);
     }
+
+    #[test]
+    fn next_power_of_two_is_checked_at_usize_boundary() {
+        assert_eq!(next_power_of_two(0), Some(1));
+        assert_eq!(next_power_of_two(5), Some(8));
+        assert_eq!(next_power_of_two((1usize << 20) + 1), Some(1usize << 21));
+        let top = 1usize << (usize::BITS - 1);
+        assert_eq!(next_power_of_two(top), Some(top));
+        assert_eq!(next_power_of_two(top + 1), None);
+        assert_eq!(next_power_of_two(usize::MAX), None);
+    }
+
+    #[test]
+    fn merkle_root_over_non_power_of_two_is_pinned() {
+        // Five leaves pad to eight. PINNED on a 64-bit host — a wasm32 run
+        // must reproduce it exactly.
+        let leaves: Vec<Vec<u8>> = (0u8..5).map(|i| vec![i]).collect();
+        let expected: Digest = [
+            0x6c, 0x4c, 0x36, 0xf0, 0xf6, 0xa5, 0xb9, 0x7f,
+            0xe3, 0x99, 0xca, 0xbb, 0x5a, 0xfa, 0xd1, 0x3e,
+            0x31, 0x2a, 0x76, 0xec, 0x9a, 0x01, 0x82, 0xc6,
+            0xac, 0xae, 0x10, 0x3d, 0x09, 0x3a, 0x34, 0xf2,
+        ];
+        assert_eq!(compute_merkle_root(&leaves).unwrap(), expected);
+    }
+
+    fn leaves(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| vec![b'l', i as u8]).collect()
+    }
+
+    #[test]
+    fn consistency_proof_round_trips_four_to_six() {
+        let new = leaves(6);
+        let old_root = compute_merkle_root(&new[..4]).unwrap();
+        let new_root = compute_merkle_root(&new).unwrap();
+        let proof = prove_consistency(&new, 4).unwrap();
+        assert_eq!(proof.len(), 1 + 3);
+        assert_eq!(verify_consistency(old_root, 4, new_root, 6, &proof), Ok(()));
+
+        // Every shape up to 17 leaves, padding on either side included.
+        for n in 1..=17 {
+            let all = leaves(n);
+            let new_root = compute_merkle_root(&all).unwrap();
+            for m in 0..=n {
+                let old_root = compute_merkle_root(&all[..m]).unwrap();
+                let proof = prove_consistency(&all, m).unwrap();
+                assert_eq!(verify_consistency(old_root, m, new_root, n, &proof), Ok(()), "{} -> {}", m, n);
+            }
+        }
+    }
+
+    #[test]
+    fn rewritten_history_is_not_a_consistent_extension() {
+        let old = leaves(4);
+        let old_root = compute_merkle_root(&old).unwrap();
+
+        // Six leaves whose second leaf differs from the old tree's.
+        let mut rewritten = leaves(6);
+        rewritten[1] = b"forged".to_vec();
+        let forged_root = compute_merkle_root(&rewritten).unwrap();
+        let proof = prove_consistency(&rewritten, 4).unwrap();
+        assert_eq!(
+            verify_consistency(old_root, 4, forged_root, 6, &proof),
+            Err(TransitionError::InvalidMerkleWitness)
+        );
+
+        // An honest proof fails against the wrong sizes or a tampered sibling.
+        let honest = leaves(6);
+        let new_root = compute_merkle_root(&honest).unwrap();
+        let mut proof = prove_consistency(&honest, 4).unwrap();
+        assert_eq!(verify_consistency(old_root, 3, new_root, 6, &proof), Err(TransitionError::InvalidMerkleWitness));
+        assert_eq!(verify_consistency(old_root, 4, new_root, 9, &proof), Err(TransitionError::InvalidMerkleWitness));
+        assert_eq!(verify_consistency(old_root, 7, new_root, 6, &proof), Err(TransitionError::InvalidSerialization));
+        proof[2][0] ^= 1;
+        assert_eq!(verify_consistency(old_root, 4, new_root, 6, &proof), Err(TransitionError::InvalidMerkleWitness));
+    }
+
+    /// Independent reference: split the padded leaf list in halves recursively.
+    fn reference_root(padded: &[Digest]) -> Digest {
+        if padded.len() == 1 {
+            return padded[0];
+        }
+        let (l, r) = padded.split_at(padded.len() / 2);
+        hash_node(&reference_root(l), &reference_root(r))
+    }
+
+    #[test]
+    fn odd_level_duplication_is_never_reached() {
+        use core::sync::atomic::Ordering;
+
+        for n in 1..=33usize {
+            let leaves: Vec<Vec<u8>> = (0..n).map(|i| vec![b'l', i as u8]).collect();
+            let mut padded: Vec<Digest> = leaves.iter().map(|l| hash_leaf(l)).collect();
+            let last = *padded.last().unwrap();
+            padded.resize(n.next_power_of_two(), last);
+            assert_eq!(compute_merkle_root(&leaves).unwrap(), reference_root(&padded),
+                "root for {} leaves diverged from the reference", n);
+        }
+        // 5, 6, 7 → 8 and 17..=31 → 32 stress every partial-level shape.
+        assert_eq!(ODD_LEVEL_DUPLICATIONS.load(Ordering::Relaxed), 0,
+            "defensive odd-level duplication ran: padding bug");
+    }
 }
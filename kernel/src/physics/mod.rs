@@ -4,3 +4,4 @@ pub mod ed25519;
 pub mod hashing;
 pub mod merkle;
 pub mod sha512;
+pub mod vdf;
@@ -146,17 +146,29 @@ fn feed_byte(state: &mut [u64; 8], pending: &mut [u8; 128], pending_len: &mut us
 /// Compute SHA-512 over an arbitrary byte slice.
 /// FIPS 180-4 §5.1.2 (padding) and §6.4 (hash computation).
 pub fn sha512(input: &[u8]) -> Digest512 {
+    sha512_slices(&[input])
+}
+
+/// Compute SHA-512 over the concatenation of `parts`, without staging them
+/// into one buffer. `sha512_slices(&[a, b]) == sha512(a || b)`.
+///
+/// Interop primitive for hosts building Ed25519 prehashes from separate fields.
+/// The kernel's own SHA-512 use is confined to Ed25519 verification.
+pub fn sha512_slices(parts: &[&[u8]]) -> Digest512 {
     let mut state = H;
     // Message length in bits — SHA-512 uses a 128-bit length field, but
     // inputs here never exceed u64::MAX bytes, so hi=0 is always safe.
-    let bit_len_lo: u64 = (input.len() as u64).wrapping_mul(8);
+    let total_len = parts.iter().fold(0u64, |acc, p| acc.wrapping_add(p.len() as u64));
+    let bit_len_lo: u64 = total_len.wrapping_mul(8);
     let bit_len_hi: u64 = 0;
 
     let mut pending = [0u8; 128];
     let mut pending_len: usize = 0;
 
-    for &b in input {
-        feed_byte(&mut state, &mut pending, &mut pending_len, b);
+    for part in parts {
+        for &b in *part {
+            feed_byte(&mut state, &mut pending, &mut pending_len, b);
+        }
     }
 
     // FIPS 180-4 §5.1.2 — append the single bit '1' (as 0x80).
@@ -249,4 +261,23 @@ mod tests {
         // SHA-256 is 32 bytes, SHA-512 is 64 bytes — first 32 bytes must differ.
         assert_ne!(h256[..], h512[..32]);
     }
+
+    #[test]
+    fn slices_match_concatenation() {
+        assert_eq!(sha512_slices(&[b"ab", b"c"]), sha512(b"abc"));
+        assert_eq!(sha512_slices(&[b"", b"abc", b""]), sha512(b"abc"));
+        // Split across the 128-byte block boundary.
+        let long = [0x5Au8; 300];
+        assert_eq!(sha512_slices(&[&long[..127], &long[127..129], &long[129..]]), sha512(&long));
+    }
+
+    #[test]
+    fn slices_single_empty_slice_is_fips_empty_vector() {
+        let expected = from_hex(
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+             47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        );
+        assert_eq!(sha512_slices(&[b""]), expected);
+        assert_eq!(sha512_slices(&[]), expected);
+    }
 }
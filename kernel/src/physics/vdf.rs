@@ -0,0 +1,40 @@
+//! VdfVerifier trait: the interface between the kernel and VDF proof verification.
+//!
+//! CONSTITUTIONAL RULE: `vdf_challenge_seed` is DERIVED, never host-supplied.
+//! The only path from the witness bundle to the seed is through a verifier's
+//! output over `StateWitnessBundle.vdf_proof`. There is no witness field that
+//! carries a seed, so a host cannot inject one.
+//!
+//! The real verifier (SNARK over the VDF evaluation) is deferred to v0.1.0.
+//! Until then `NoVdf` is plugged in: no proof → all-zero seed, any proof → rejected.
+
+use crate::physics::hashing::Digest;
+use crate::TransitionError;
+
+/// The VDF verification interface.
+pub trait VdfVerifier {
+    /// Verify `proof` for the VDF evaluated on `challenge` and return its output,
+    /// which becomes the new epoch's `vdf_challenge_seed`.
+    ///
+    /// Must be deterministic: the same `(challenge, proof)` always yields the
+    /// same result. Returns `InvalidVdfProof` if the proof does not verify.
+    fn verify_and_extract_seed(
+        &self,
+        challenge: &Digest,
+        proof: &[u8],
+    ) -> Result<Digest, TransitionError>;
+}
+
+/// The stub verifier used until VDF verification lands.
+/// Accepts no proof at all; the transition then keeps the all-zero seed.
+pub struct NoVdf;
+
+impl VdfVerifier for NoVdf {
+    fn verify_and_extract_seed(
+        &self,
+        _challenge: &Digest,
+        _proof: &[u8],
+    ) -> Result<Digest, TransitionError> {
+        Err(TransitionError::InvalidVdfProof)
+    }
+}
@@ -0,0 +1,204 @@
+//! Serde tooling format (feature "serde").
+//!
+//! Field encoders for the `serde` feature: byte arrays and byte strings as
+//! lowercase hex, integers as decimal strings.
+//!
+//! TOOLING FORMAT ONLY. It is lenient JSON for explorers and indexers, not the
+//! canonical form: key order, whitespace and hex case are not enforced on input.
+//! Anything deserialized this way must be re-canonicalized (`canonical_bytes`,
+//! `to_canonical_json`) before any consensus use.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+use crate::compat::{String, Vec};
+
+fn encode(bytes: &[u8]) -> String {
+    crate::physics::hashing::to_hex(bytes)
+}
+
+// Case-insensitive on input; `from_hex` itself accepts lowercase only.
+fn decode<E: Error>(s: &str) -> Result<Vec<u8>, E> {
+    crate::physics::hashing::from_hex(s.to_ascii_lowercase().as_bytes())
+        .map_err(|_| E::custom("invalid hex string"))
+}
+
+/// `[u8; N]` (digests, pubkeys, signatures) as exactly `2N` hex chars.
+pub mod hex_array {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(v: &[u8; N], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&encode(v))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(d: D) -> Result<[u8; N], D::Error> {
+        let bytes = decode::<D::Error>(&String::deserialize(d)?)?;
+        bytes.try_into().map_err(|_| D::Error::custom("wrong hex length"))
+    }
+}
+
+/// `Vec<u8>` as hex of any even length.
+pub mod hex_bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(v: &[u8], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&encode(v))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+        decode(&String::deserialize(d)?)
+    }
+}
+
+/// `Option<Vec<u8>>` as hex or `null`.
+pub mod hex_opt_bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(v: &Option<Vec<u8>>, s: S) -> Result<S::Ok, S::Error> {
+        match v {
+            Some(bytes) => s.serialize_some(&encode(bytes)),
+            None        => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Vec<u8>>, D::Error> {
+        Option::<String>::deserialize(d)?.map(|h| decode(&h)).transpose()
+    }
+}
+
+/// `MerklePath` as an array of nodes. Deserialization goes through
+/// `MerklePath::new`, so the depth limit holds for tooling input too.
+pub mod merkle_path {
+    use super::*;
+    use crate::state::witness::{MerklePath, MerklePathNode};
+    use serde::Serialize;
+
+    pub fn serialize<S: Serializer>(v: &MerklePath, s: S) -> Result<S::Ok, S::Error> {
+        v.nodes.serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<MerklePath, D::Error> {
+        MerklePath::new(Vec::<MerklePathNode>::deserialize(d)?)
+            .map_err(|_| D::Error::custom("merkle path exceeds MAX_MERKLE_DEPTH"))
+    }
+}
+
+/// `mutation_authors` as `[[index, "pubkey hex"], ...]`.
+pub mod mutation_authors {
+    use super::*;
+    use serde::Serialize;
+
+    pub fn serialize<S: Serializer>(v: &[(usize, [u8; 32])], s: S) -> Result<S::Ok, S::Error> {
+        v.iter().map(|(i, pk)| (*i, encode(pk))).collect::<Vec<_>>().serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<(usize, [u8; 32])>, D::Error> {
+        Vec::<(usize, String)>::deserialize(d)?
+            .into_iter()
+            .map(|(i, h)| {
+                let pk = decode::<D::Error>(&h)?.try_into().map_err(|_| D::Error::custom("wrong hex length"))?;
+                Ok((i, pk))
+            })
+            .collect()
+    }
+}
+
+/// `u128` / `u64` as a decimal string (JSON numbers lose precision past 2^53).
+pub mod decimal {
+    use super::*;
+    use core::fmt::Display;
+    use core::str::FromStr;
+
+    pub fn serialize<S: Serializer, T: Display>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(v)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, T: FromStr>(d: D) -> Result<T, D::Error> {
+        String::deserialize(d)?.parse().map_err(|_| D::Error::custom("invalid decimal string"))
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use crate::physics::hashing::sha256;
+    use crate::physics::merkle::MAX_MERKLE_DEPTH;
+    use crate::state::epoch::EpochState;
+    use crate::state::witness::{
+        compute_bundle_hash, EntropyStats, LeafMutation, MerklePath, MerklePathNode, NodePosition,
+        StateWitnessBundle, ValidatorSignature,
+    };
+
+    #[test]
+    fn epoch_state_round_trips_with_hex_and_decimal_strings() {
+        let mut state = EpochState::genesis().unwrap();
+        state.entropy_metric_scaled = u128::MAX;
+        let json = serde_json::to_string(&state).unwrap();
+        // u128 beyond 2^53 survives as a string; digests are lowercase hex.
+        assert!(json.contains(&format!("\"entropy_metric_scaled\":\"{}\"", u128::MAX)));
+        assert!(json.contains("\"epoch_number\":\"0\""));
+        let root_hex = crate::physics::hashing::to_hex(&state.state_root);
+        assert!(json.contains(&format!("\"state_root\":\"{}\"", root_hex)));
+
+        let back: EpochState = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, state);
+    }
+
+    #[test]
+    fn witness_bundle_round_trips() {
+        let bundle = StateWitnessBundle {
+            bond_witnesses: vec![],
+            entropy_stats: EntropyStats {
+                active_bonded_magnitude_raw: 500_000_000_000,
+                total_supply_raw: 1_000_000_000_000,
+                unique_active_validators: 5,
+                optimal_validator_count: 10,
+            },
+            impact_witnesses: vec![],
+            mutation_authors: vec![(0, [0x11; 32])],
+            validator_signatures: vec![ValidatorSignature {
+                validator_pubkey: [0x11; 32],
+                signature: [0xEE; 64],
+            }],
+            validator_witnesses: vec![LeafMutation {
+                key: b"ab".to_vec(),
+                old_value: vec![],
+                new_value: b"{\"k\":1}".to_vec(),
+                path: MerklePath::new(vec![MerklePathNode {
+                    sibling: sha256(b"sibling"),
+                    position: NodePosition::Right,
+                }]).unwrap(),
+                append_proof: None,
+            }],
+            vdf_proof: Some(vec![0xDE, 0xAD]),
+        };
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert!(json.contains("\"position\":\"right\""));
+        assert!(json.contains("\"vdf_proof\":\"dead\""));
+
+        let back: StateWitnessBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(compute_bundle_hash(&back), compute_bundle_hash(&bundle));
+        assert_eq!(back.entropy_stats, bundle.entropy_stats);
+        assert_eq!(back.validator_signatures, bundle.validator_signatures);
+        assert_eq!(back.validator_witnesses[0].path, bundle.validator_witnesses[0].path);
+        assert_eq!(back.vdf_proof, bundle.vdf_proof);
+        assert_eq!(back.mutation_authors, bundle.mutation_authors);
+    }
+
+    #[test]
+    fn malformed_tooling_input_is_rejected() {
+        let node = serde_json::json!({ "sibling": "00".repeat(32), "position": "left" });
+        let too_deep = serde_json::json!({
+            "key": "6162", "old_value": "", "new_value": "",
+            "path": vec![node; MAX_MERKLE_DEPTH + 1],
+        });
+        assert!(serde_json::from_value::<LeafMutation>(too_deep).is_err());
+
+        let short_key = serde_json::json!({ "validator_pubkey": "11", "signature": "ee".repeat(64) });
+        assert!(serde_json::from_value::<ValidatorSignature>(short_key).is_err());
+
+        let bad_digit = serde_json::json!({ "sibling": "zz".repeat(32), "position": "left" });
+        assert!(serde_json::from_value::<MerklePathNode>(bad_digit).is_err());
+    }
+}
@@ -26,10 +26,23 @@ pub fn apply_decay(balance: Fixed) -> Result<Fixed, TransitionError> {
     balance.mul_scaled(factor)
 }
 
+/// Apply one epoch of decay and also return the amount removed from `balance`.
+///
+/// Returns `(decayed, removed)` with `decayed + removed == balance` exactly.
+/// `decayed` is identical to `apply_decay`. `removed` is the decay amount
+/// including the truncated dust, so a host tracking a burn pool can account
+/// for every raw unit destroyed. It exceeds the exact (unrounded) decay amount
+/// by less than one raw unit.
+pub fn apply_decay_with_remainder(balance: Fixed) -> Result<(Fixed, Fixed), TransitionError> {
+    let decayed = apply_decay(balance)?;
+    // Cannot underflow: DECAY_FACTOR_SCALED < SCALE, so decayed ≤ balance.
+    let removed = balance.checked_sub(decayed)?;
+    Ok((decayed, removed))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::math::fixed::SCALE;
 
     #[test]
     fn decay_reduces_balance() {
@@ -48,4 +61,27 @@ mod tests {
             expected_raw
         );
     }
+
+    #[test]
+    fn decay_with_remainder_sums_to_balance() {
+        for raw in [0u128, 1, 7, 999_999, 1_000_000_000_000, 123_456_789_012_345, 10u128.pow(24)] {
+            let balance = Fixed::from_raw(raw).unwrap();
+            let (decayed, removed) = apply_decay_with_remainder(balance).unwrap();
+            assert_eq!(decayed, apply_decay(balance).unwrap());
+            assert_eq!(decayed.checked_add(removed).unwrap(), balance);
+        }
+    }
+
+    #[test]
+    fn decay_remainder_is_within_one_raw_unit_of_exact() {
+        use crate::math::fixed::SCALE;
+        for raw in [1u128, 3, 1_000_000_000_001, 987_654_321_987_654_321] {
+            let (_, removed) = apply_decay_with_remainder(Fixed::from_raw(raw).unwrap()).unwrap();
+            // Exact decay amount, scaled by SCALE: raw × (1 − factor).
+            let exact_scaled = raw * (SCALE - DECAY_FACTOR_SCALED);
+            let removed_scaled = removed.raw() * SCALE;
+            assert!(removed_scaled >= exact_scaled, "removed must include the dust");
+            assert!(removed_scaled - exact_scaled < SCALE, "dust must be under one raw unit");
+        }
+    }
 }
@@ -6,6 +6,9 @@
 //!
 //! Both ratios are computed as Fixed values (scaled to SCALE) before multiplication.
 //! This prevents inflation when validators cartel or supply consolidates.
+//!
+//! This is the ONLY consensus-valid entropy formula. Parameterized variants for
+//! research live in `state::entropy_experimental` and are never committed.
 
 use crate::math::fixed::Fixed;
 use crate::TransitionError;
@@ -40,3 +43,29 @@ pub fn compute_entropy(
     // Global_Entropy = bonded_ratio * validator_ratio
     bonded_ratio.mul_scaled(validator_ratio)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_inactive_epoch_has_exactly_zero_entropy() {
+        // Quiescent network: nothing bonded, no validator participated.
+        let supply = Fixed::from_units(1_000).unwrap();
+        let entropy = compute_entropy(Fixed::zero(), supply, 0, 10).unwrap();
+        assert!(entropy.is_zero());
+    }
+
+    #[test]
+    fn zero_participation_alone_yields_zero() {
+        let half = Fixed::from_units(500).unwrap();
+        let supply = Fixed::from_units(1_000).unwrap();
+        assert!(compute_entropy(half, supply, 0, 10).unwrap().is_zero());
+    }
+
+    #[test]
+    fn zero_bonded_alone_yields_zero() {
+        let supply = Fixed::from_units(1_000).unwrap();
+        assert!(compute_entropy(Fixed::zero(), supply, 10, 10).unwrap().is_zero());
+    }
+}
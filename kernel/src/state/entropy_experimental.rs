@@ -0,0 +1,153 @@
+//! Parameterized entropy formulas for governance research.
+//!
+//! ┌──────────────────────────────────────────────────────────────────────────┐
+//! │ NON-CONSENSUS. NOTHING IN THIS MODULE MAY BE CALLED FROM `apply_epoch`.  │
+//! │ The only consensus-valid entropy is `state::entropy::compute_entropy`.   │
+//! │ A root committed with any other formula is a fork, not an experiment.    │
+//! └──────────────────────────────────────────────────────────────────────────┘
+//!
+//! GENERALIZED FORMULA:
+//! ```text
+//! entropy = bonded_ratio^bonded_exponent
+//!         * validator_ratio^validator_exponent
+//!         * (1 - gini_weight * gini)
+//! ```
+//!
+//! `EntropyParams::constitutional()` (exponents 1, gini_weight 0) reproduces
+//! `compute_entropy` bit-for-bit: multiplying by exactly 1.0 is the identity.
+
+use crate::math::fixed::Fixed;
+use crate::state::witness::EntropyStats;
+use crate::TransitionError;
+
+/// Weightings for `compute_entropy_experimental`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EntropyParams {
+    /// Power applied to `active_bonded / total_supply`. 0 drops the term.
+    pub bonded_exponent: u32,
+    /// Power applied to `unique_active / optimal_count`. 0 drops the term.
+    pub validator_exponent: u32,
+    /// Host-measured Gini coefficient of bonded stake, in [0, 1].
+    pub gini: Fixed,
+    /// How strongly concentration reduces entropy, in [0, 1]. 0 disables it.
+    pub gini_weight: Fixed,
+}
+
+impl EntropyParams {
+    /// The preset that exactly reproduces the constitutional `compute_entropy`.
+    pub fn constitutional() -> Self {
+        EntropyParams {
+            bonded_exponent:    1,
+            validator_exponent: 1,
+            gini:               Fixed::zero(),
+            gini_weight:        Fixed::zero(),
+        }
+    }
+}
+
+/// Experimental entropy under `params`. NON-CONSENSUS (see module doc).
+///
+/// Returns the same errors as `compute_entropy` for invalid stats, and
+/// `MathUnderflow` if `gini_weight * gini` exceeds 1.0.
+pub fn compute_entropy_experimental(
+    stats:  &EntropyStats,
+    params: &EntropyParams,
+) -> Result<Fixed, TransitionError> {
+    stats.validate()?;
+    let total_supply = Fixed::from_raw(stats.total_supply_raw)?;
+    if total_supply.is_zero() {
+        return Err(TransitionError::DivisionByZero);
+    }
+    let one = Fixed::ONE;
+
+    let bonded_ratio = Fixed::from_raw(stats.active_bonded_magnitude_raw)?.div_scaled(total_supply)?;
+    let validator_ratio = Fixed::from_units(stats.unique_active_validators as u128)?
+        .div_scaled(Fixed::from_units(stats.optimal_validator_count as u128)?)?;
+
+    let concentration = params.gini_weight.mul_scaled(params.gini)?;
+    let spread_factor = one.checked_sub(concentration)?;
+
+    let mut entropy = one;
+    for _ in 0..params.bonded_exponent {
+        entropy = entropy.mul_scaled(bonded_ratio)?;
+    }
+    for _ in 0..params.validator_exponent {
+        entropy = entropy.mul_scaled(validator_ratio)?;
+    }
+    entropy.mul_scaled(spread_factor)
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::entropy::compute_entropy;
+
+    /// 50% bonded, 5 of 10 validators active → constitutional entropy 0.25.
+    fn standard_stats() -> EntropyStats {
+        EntropyStats {
+            active_bonded_magnitude_raw: 500_000_000_000,
+            total_supply_raw:            1_000_000_000_000,
+            unique_active_validators:    5,
+            optimal_validator_count:     10,
+        }
+    }
+
+    fn constitutional(stats: &EntropyStats) -> Fixed {
+        compute_entropy(
+            Fixed::from_raw(stats.active_bonded_magnitude_raw).unwrap(),
+            Fixed::from_raw(stats.total_supply_raw).unwrap(),
+            stats.unique_active_validators,
+            stats.optimal_validator_count,
+        ).unwrap()
+    }
+
+    #[test]
+    fn constitutional_preset_reproduces_compute_entropy() {
+        let params = EntropyParams::constitutional();
+        let stats = standard_stats();
+        assert_eq!(compute_entropy_experimental(&stats, &params).unwrap(), constitutional(&stats));
+
+        // Also on a truncating ratio (1/3 bonded, 7 of 9 active).
+        let odd = EntropyStats {
+            active_bonded_magnitude_raw: 1_000_000_000_000,
+            total_supply_raw:            3_000_000_000_000,
+            unique_active_validators:    7,
+            optimal_validator_count:     9,
+        };
+        assert_eq!(compute_entropy_experimental(&odd, &params).unwrap(), constitutional(&odd));
+    }
+
+    #[test]
+    fn gini_weighting_changes_the_value() {
+        let params = EntropyParams {
+            gini:        Fixed::from_raw(400_000_000_000).unwrap(), // 0.4
+            gini_weight: Fixed::from_units(1).unwrap(),
+            ..EntropyParams::constitutional()
+        };
+        // 0.25 * (1 - 0.4) = 0.15
+        let e = compute_entropy_experimental(&standard_stats(), &params).unwrap();
+        assert_eq!(e, Fixed::from_raw(150_000_000_000).unwrap());
+        assert_ne!(e, constitutional(&standard_stats()));
+    }
+
+    #[test]
+    fn squared_bonded_term_changes_the_value() {
+        let params = EntropyParams { bonded_exponent: 2, ..EntropyParams::constitutional() };
+        // 0.5^2 * 0.5 = 0.125
+        let e = compute_entropy_experimental(&standard_stats(), &params).unwrap();
+        assert_eq!(e, Fixed::from_raw(125_000_000_000).unwrap());
+    }
+
+    #[test]
+    fn overweighted_gini_is_rejected() {
+        let params = EntropyParams {
+            gini:        Fixed::from_units(1).unwrap(),
+            gini_weight: Fixed::from_units(2).unwrap(),
+            ..EntropyParams::constitutional()
+        };
+        assert_eq!(compute_entropy_experimental(&standard_stats(), &params), Err(TransitionError::MathUnderflow));
+    }
+}
@@ -3,7 +3,7 @@
 //! # What This Is
 //!
 //! `EpochState` is the only thing the consensus layer needs to agree on.
-//! It is a flat set of 8 fixed-width fields, all `[u8; 32]` or `u128`.
+//! It is a flat set of 11 fixed-width fields, all `[u8; 32]`, `u128` or `u64`.
 //! There are no generics, no trait bounds, no heap allocation, no Vec.
 //! The struct is fully stack-allocated and copy-friendly.
 //!
@@ -20,12 +20,14 @@
 //!
 //! 1. `bond_pool_root`         — hex string (64 chars)
 //! 2. `entropy_metric_scaled`  — decimal u128 string (raw Fixed inner value)
-//! 3. `epoch_number`           — decimal u64 string
-//! 4. `impact_pool_root`       — hex string (64 chars)
-//! 5. `kernel_hash`            — hex string (64 chars)
-//! 6. `previous_root`          — hex string (64 chars)
-//! 7. `validator_set_root`     — hex string (64 chars)
-//! 8. `vdf_challenge_seed`     — hex string (64 chars)
+//! 3. `epoch_duration_secs`    — decimal u64 string
+//! 4. `epoch_number`           — decimal u64 string
+//! 5. `governance_root`        — hex string (64 chars)
+//! 6. `impact_pool_root`       — hex string (64 chars)
+//! 7. `kernel_hash`            — hex string (64 chars)
+//! 8. `previous_root`          — hex string (64 chars)
+//! 9. `validator_set_root`     — hex string (64 chars)
+//! 10. `vdf_challenge_seed`    — hex string (64 chars)
 //!
 //! This ordering is alphabetical by key name, which is what `canonicalize()` enforces.
 //! It is documented here explicitly so that it survives future code refactors.
@@ -39,6 +41,16 @@
 //! - `kernel_hash` is the SHA-256 of the WASM kernel binary that produced this state.
 //!   It prevents cross-kernel fraud proof replay and detects silent binary upgrades.
 //!
+//! - `governance_root` commits to the active `GovernanceParams`. Like
+//!   `epoch_duration_secs` it is fixed at genesis and carried forward unchanged,
+//!   so any parameter change is a visible root change in the chain. The
+//!   placeholder genesis commits an all-zero root (no parameter set), and no
+//!   transition checks witness values against it.
+//!
+//! - `epoch_duration_secs` records the intended epoch cadence. The kernel reads
+//!   no clock: the value is fixed at genesis, carried forward unchanged by every
+//!   transition, and bound into the validator signing root.
+//!
 //! - `previous_root` chains this epoch to the one before it.
 //!   The thermodynamic arrow of time is cryptographically enforced.
 //!
@@ -46,8 +58,10 @@
 //!   the circular dependency. It is always the LAST field to be computed.
 
 use crate::math::fixed::Fixed;
-use crate::physics::hashing::{sha256, Digest};
+use crate::physics::hashing::{ct_eq, sha256, to_hex, Digest, Sha256};
 use crate::physics::canonical_json::canonicalize;
+use crate::state::witness::{compute_bundle_hash, compute_epoch_signing_root, EntropyStats, StateWitnessBundle};
+use crate::transition::TransitionReceipt;
 use crate::TransitionError;
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -57,6 +71,22 @@ use crate::TransitionError;
 /// Maximum payloads (ProofOfImpact + VouchBond combined) accepted per epoch.
 pub const MAX_PAYLOADS_PER_EPOCH: usize = 10_000;
 
+/// Intended epoch cadence recorded by `genesis()`: 30 days, the federation
+/// voting-weight lock period (`federation_calendar.md`).
+pub const DEFAULT_EPOCH_DURATION_SECS: u64 = 2_592_000;
+
+/// `previous_root` of the genesis state: `SHA256(b"GENESIS")`.
+///
+/// Distinct from all-zeros, so a non-genesis state whose `previous_root` was
+/// wrongly zeroed is detectable (`verify_progress`) rather than looking like
+/// genesis.
+pub const GENESIS_PREVIOUS_ROOT: Digest = [
+    0x90, 0x11, 0x31, 0xd8, 0x38, 0xb1, 0x7a, 0xac,
+    0x0f, 0x78, 0x85, 0xb8, 0x1e, 0x03, 0xcb, 0xdc,
+    0x9f, 0x51, 0x57, 0xa0, 0x03, 0x43, 0xd3, 0x0a,
+    0xb2, 0x20, 0x83, 0x68, 0x5e, 0xd1, 0x41, 0x6a,
+];
+
 /// Fraud proofs older than this many epochs are permanently rejected.
 pub const MAX_FRAUD_WINDOW_EPOCHS: u64 = 1;
 
@@ -72,43 +102,62 @@ pub const MAX_FRAUD_WINDOW_EPOCHS: u64 = 1;
 /// This keeps the struct small enough to fit in WASM memory budgets
 /// regardless of how many identities exist in the network.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EpochState {
     // ── Serialized fields (alphabetically, all included in state_root) ─────
 
     /// Merkle root committing to the set of active `VouchBond` locks.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub bond_pool_root: Digest,
 
     /// Global entropy metric for this epoch.
     /// Stored as the **raw u128 inner value** of `crate::math::fixed::Fixed`.
     /// Scale factor is `SCALE = 10^12`. Representation is immutable.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::decimal"))]
     pub entropy_metric_scaled: u128,
 
+    /// Host-declared epoch cadence in seconds. Set at genesis and carried
+    /// forward unchanged; validators sign over it (see `compute_epoch_signing_root`).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::decimal"))]
+    pub epoch_duration_secs: u64,
+
     /// Monotonically increasing epoch counter. Genesis is 0.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::decimal"))]
     pub epoch_number: u64,
 
+    /// Merkle root of the active protocol parameters (`GovernanceParams::root`).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
+    pub governance_root: Digest,
+
     /// Merkle root committing to all validated `ProofOfImpact` records.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub impact_pool_root: Digest,
 
     /// SHA-256 of the WASM kernel binary that produced this state.
     /// Binds on-chain commitments to a specific auditable kernel version.
     /// Prevents cross-kernel fraud proof replay attacks.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub kernel_hash: Digest,
 
     /// `state_root` of the immediately preceding epoch.
     /// The chain of `previous_root` hashes is the thermodynamic arrow of time.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub previous_root: Digest,
 
     // ── Self-committing hash (NOT included in its own serialization) ───────
 
     /// SHA-256 of the canonical serialization of all other fields.
     /// Computed last. Excluded from the canonical bytes it hashes over.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub state_root: Digest,
 
     /// Merkle root committing to the active validator set and stake weights.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub validator_set_root: Digest,
 
     /// VDF challenge seed used to derive the NEXT epoch's sortition randomness.
     /// Prevents look-ahead attacks — the seed is only known when this epoch closes.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub vdf_challenge_seed: Digest,
 }
 
@@ -152,7 +201,7 @@ fn encode_u64(n: u64) -> Vec<u8> {
 // Canonical JSON builder
 // ──────────────────────────────────────────────────────────────────────────────
 
-/// Build the canonical JSON bytes for the 8 fields that contribute to `state_root`.
+/// Build the canonical JSON bytes for the 10 fields that contribute to `state_root`.
 /// Fields are emitted in alphabetical order (matching what `canonicalize()` enforces).
 /// The `state_root` field is deliberately excluded.
 fn build_commitment_json(s: &EpochState) -> Vec<u8> {
@@ -164,8 +213,12 @@ fn build_commitment_json(s: &EpochState) -> Vec<u8> {
     out.extend_from_slice(&encode_digest(&s.bond_pool_root));
     out.extend_from_slice(b"\",\"entropy_metric_scaled\":\"");
     out.extend_from_slice(&encode_u128(s.entropy_metric_scaled));
+    out.extend_from_slice(b"\",\"epoch_duration_secs\":\"");
+    out.extend_from_slice(&encode_u64(s.epoch_duration_secs));
     out.extend_from_slice(b"\",\"epoch_number\":\"");
     out.extend_from_slice(&encode_u64(s.epoch_number));
+    out.extend_from_slice(b"\",\"governance_root\":\"");
+    out.extend_from_slice(&encode_digest(&s.governance_root));
     out.extend_from_slice(b"\",\"impact_pool_root\":\"");
     out.extend_from_slice(&encode_digest(&s.impact_pool_root));
     out.extend_from_slice(b"\",\"kernel_hash\":\"");
@@ -186,25 +239,30 @@ fn build_commitment_json(s: &EpochState) -> Vec<u8> {
 // ──────────────────────────────────────────────────────────────────────────────
 
 impl EpochState {
-    /// Returns the placeholder genesis state (epoch 0, all-zero roots).
+    /// Returns the placeholder genesis state (epoch 0, all-zero roots,
+    /// `previous_root = GENESIS_PREVIOUS_ROOT`, `DEFAULT_EPOCH_DURATION_SECS` cadence).
     ///
     /// In production this is replaced by a Genesis Manifest signed by the
     /// founding committee. All-zero roots are valid placeholders for alpha testing.
-    pub fn genesis() -> Self {
-        let mut s = EpochState {
+    ///
+    /// A commitment failure is propagated, never masked: an all-zero fallback
+    /// root would silently fork from the pinned genesis vector.
+    pub fn genesis() -> Result<Self, TransitionError> {
+        let s = EpochState {
             bond_pool_root:        [0u8; 32],
             entropy_metric_scaled: 0,
+            epoch_duration_secs:   DEFAULT_EPOCH_DURATION_SECS,
             epoch_number:          0,
+            governance_root:       [0u8; 32],
             impact_pool_root:      [0u8; 32],
             kernel_hash:           [0u8; 32],
-            previous_root:         [0u8; 32],
+            previous_root:         GENESIS_PREVIOUS_ROOT,
             state_root:            [0u8; 32],
             validator_set_root:    [0u8; 32],
             vdf_challenge_seed:    [0u8; 32],
         };
         // Compute and assign the actual genesis state_root.
-        s.state_root = s.compute_state_root().unwrap_or([0u8; 32]);
-        s
+        s.commit()
     }
 
     /// Return the `entropy_metric_scaled` field as a typed `Fixed(u128)`.
@@ -241,6 +299,39 @@ impl EpochState {
         Ok(sha256(&bytes))
     }
 
+    /// Check that `state_root` is the root of this state's own fields.
+    ///
+    /// Recomputes `compute_state_root()` and compares in constant time.
+    /// Returns `StateRootMismatch` if a field was altered after `commit()`.
+    pub fn verify_self(&self) -> Result<(), TransitionError> {
+        if !ct_eq(&self.compute_state_root()?, &self.state_root) {
+            return Err(TransitionError::StateRootMismatch);
+        }
+        Ok(())
+    }
+
+    /// `true` iff this is a genesis state: epoch 0 chained to `GENESIS_PREVIOUS_ROOT`.
+    pub fn is_genesis(&self) -> bool {
+        self.epoch_number == 0 && self.previous_root == GENESIS_PREVIOUS_ROOT
+    }
+
+    /// Check that this state moved the chain forward: `state_root != previous_root`.
+    ///
+    /// Every honest transition increments `epoch_number`, so its root always
+    /// differs from the parent's; equality means a replayed or stalled state.
+    /// A non-genesis state must also chain to a real root: an all-zero
+    /// `previous_root` means the link was never set. Genesis (epoch 0) is
+    /// exempt: its `previous_root` is a sentinel, not the root of any state.
+    /// Returns `InvalidSerialization` on violation.
+    pub fn verify_progress(&self) -> Result<(), TransitionError> {
+        if self.epoch_number != 0
+            && (self.state_root == self.previous_root || self.previous_root == [0u8; 32])
+        {
+            return Err(TransitionError::InvalidSerialization);
+        }
+        Ok(())
+    }
+
     /// Assign the computed `state_root` and return `self`.
     ///
     /// Call this as the LAST step of state construction, after all other fields
@@ -249,6 +340,194 @@ impl EpochState {
         self.state_root = self.compute_state_root()?;
         Ok(self)
     }
+
+    /// Set the three pool roots and re-`commit()`.
+    ///
+    /// For building test fixtures and migration states; every other field,
+    /// including `previous_root`, is kept as-is.
+    pub fn with_roots(
+        mut self,
+        validator: Digest,
+        impact:    Digest,
+        bond:      Digest,
+    ) -> Result<Self, TransitionError> {
+        self.validator_set_root = validator;
+        self.impact_pool_root   = impact;
+        self.bond_pool_root     = bond;
+        self.commit()
+    }
+
+    /// The chain-navigation summary of this state (see `EpochHeader`).
+    pub fn header(&self) -> EpochHeader {
+        EpochHeader {
+            epoch_number:  self.epoch_number,
+            state_root:    self.state_root,
+            previous_root: self.previous_root,
+            kernel_hash:   self.kernel_hash,
+        }
+    }
+
+    /// Short display ID: `epoch-<number>-<first 4 bytes of state_root as hex>`,
+    /// e.g. `epoch-0-f9b24114` for genesis. For logs and explorers only — 32
+    /// bits of root can collide, so never key consensus data on it.
+    pub fn epoch_id(&self) -> String {
+        format!("epoch-{}-{}", self.epoch_number, to_hex(&self.state_root[..4]))
+    }
+
+    /// Equality on every field except `state_root`, for comparing a candidate
+    /// with a proposal before either is committed (placeholder roots differ).
+    ///
+    /// Destructures `self` so that adding a field fails to compile here.
+    pub fn eq_precommit(&self, other: &EpochState) -> bool {
+        let EpochState {
+            bond_pool_root,
+            entropy_metric_scaled,
+            epoch_duration_secs,
+            epoch_number,
+            governance_root,
+            impact_pool_root,
+            kernel_hash,
+            previous_root,
+            state_root: _,
+            validator_set_root,
+            vdf_challenge_seed,
+        } = self;
+        *bond_pool_root == other.bond_pool_root
+            && *entropy_metric_scaled == other.entropy_metric_scaled
+            && *epoch_duration_secs == other.epoch_duration_secs
+            && *epoch_number == other.epoch_number
+            && *governance_root == other.governance_root
+            && *impact_pool_root == other.impact_pool_root
+            && *kernel_hash == other.kernel_hash
+            && *previous_root == other.previous_root
+            && *validator_set_root == other.validator_set_root
+            && *vdf_challenge_seed == other.vdf_challenge_seed
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Header
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Domain separation prefix for header hashes (leaf=0x00, node=0x01, signing=0x02).
+const HEADER_DOMAIN_PREFIX: u8 = 0x03;
+
+/// The chain-navigation fields of an `EpochState`, for explorers and indexers.
+///
+/// NOT a consensus commitment: `header_hash` covers only these four fields, so
+/// two states that differ only in pool roots, entropy or seed share a header
+/// hash. `state_root` is what commits to the whole state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochHeader {
+    pub epoch_number:  u64,
+    pub state_root:    Digest,
+    pub previous_root: Digest,
+    pub kernel_hash:   Digest,
+}
+
+impl EpochHeader {
+    /// `SHA256(0x03 || epoch_number_be8 || state_root || previous_root || kernel_hash)`.
+    pub fn header_hash(&self) -> Digest {
+        Sha256::new()
+            .update(&[HEADER_DOMAIN_PREFIX])
+            .update(&self.epoch_number.to_be_bytes())
+            .update_digest(&self.state_root)
+            .update_digest(&self.previous_root)
+            .update_digest(&self.kernel_hash)
+            .finalize()
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Signing message
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// The exact 32 bytes a validator signs to authorize the transition `prev → prev + 1`.
+///
+/// One-call form of `compute_bundle_hash` followed by `compute_epoch_signing_root`,
+/// for key custodians (hardware wallets) that do not run the kernel.
+/// Returns `MathOverflow` if `prev.epoch_number` is `u64::MAX`.
+pub fn epoch_signing_message(
+    prev:        &EpochState,
+    witness:     &StateWitnessBundle,
+    kernel_hash: &Digest,
+) -> Result<Digest, TransitionError> {
+    let next_epoch_number = prev
+        .epoch_number
+        .checked_add(1)
+        .ok_or(TransitionError::MathOverflow)?;
+    let bundle_hash = compute_bundle_hash(witness);
+    Ok(compute_epoch_signing_root(
+        &prev.state_root,
+        &bundle_hash,
+        next_epoch_number,
+        kernel_hash,
+        prev.epoch_duration_secs,
+    ))
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Next-epoch entropy inputs
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Raw `Fixed` supply movements during one epoch, carried by
+/// `TransitionReceipt::supply`.
+///
+/// `apply_epoch` neither mints (no emission policy is wired in) nor slashes in
+/// v0.0.2, so it reports none. A host that applies emission or slashing
+/// outside the kernel records the amounts in the receipt for `next_entropy_stats`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SupplyDeltas {
+    /// Newly created supply.
+    pub minted_raw:   u128,
+    /// Supply destroyed outside slashing.
+    pub burned_raw:   u128,
+    /// Supply destroyed by slashing; always taken from bonded stake.
+    pub slashed_raw:  u128,
+    /// Stake newly bonded (moves within supply; total unchanged).
+    pub bonded_raw:   u128,
+    /// Stake unbonded (moves within supply; total unchanged).
+    pub unbonded_raw: u128,
+}
+
+/// The entropy stats a host should declare for the epoch after the one that
+/// produced `receipt`, from the supply movements in `receipt.supply`.
+///
+/// ```text
+/// total_supply_raw            += minted - burned - slashed
+/// active_bonded_magnitude_raw += bonded - unbonded - slashed
+/// ```
+///
+/// Validator counts are carried unchanged (they are host observations, not
+/// supply arithmetic). Returns `MathOverflow` / `MathUnderflow` on a checked
+/// step, and any `EntropyStats::validate` error on the result.
+pub fn next_entropy_stats(
+    prev_stats: &EntropyStats,
+    receipt:    &TransitionReceipt,
+) -> Result<EntropyStats, TransitionError> {
+    let deltas = &receipt.supply;
+    let total_supply_raw = prev_stats
+        .total_supply_raw
+        .checked_add(deltas.minted_raw)
+        .ok_or(TransitionError::MathOverflow)?
+        .checked_sub(deltas.burned_raw)
+        .and_then(|t| t.checked_sub(deltas.slashed_raw))
+        .ok_or(TransitionError::MathUnderflow)?;
+    let active_bonded_magnitude_raw = prev_stats
+        .active_bonded_magnitude_raw
+        .checked_add(deltas.bonded_raw)
+        .ok_or(TransitionError::MathOverflow)?
+        .checked_sub(deltas.unbonded_raw)
+        .and_then(|b| b.checked_sub(deltas.slashed_raw))
+        .ok_or(TransitionError::MathUnderflow)?;
+
+    let next = EntropyStats {
+        active_bonded_magnitude_raw,
+        total_supply_raw,
+        ..*prev_stats
+    };
+    next.validate()?;
+    Ok(next)
 }
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -266,7 +545,9 @@ mod tests {
         let s = EpochState {
             bond_pool_root:        [0u8; 32],
             entropy_metric_scaled: 0,
+            epoch_duration_secs:   DEFAULT_EPOCH_DURATION_SECS,
             epoch_number:          0,
+            governance_root:       [0u8; 32],
             impact_pool_root:      [0u8; 32],
             kernel_hash:           [0u8; 32],
             previous_root:         [0u8; 32],
@@ -276,7 +557,7 @@ mod tests {
         };
 
         let bytes = s.canonical_bytes().unwrap();
-        let expected = br#"{"bond_pool_root":"0000000000000000000000000000000000000000000000000000000000000000","entropy_metric_scaled":"0","epoch_number":"0","impact_pool_root":"0000000000000000000000000000000000000000000000000000000000000000","kernel_hash":"0000000000000000000000000000000000000000000000000000000000000000","previous_root":"0000000000000000000000000000000000000000000000000000000000000000","validator_set_root":"0000000000000000000000000000000000000000000000000000000000000000","vdf_challenge_seed":"0000000000000000000000000000000000000000000000000000000000000000"}"#;
+        let expected = br#"{"bond_pool_root":"0000000000000000000000000000000000000000000000000000000000000000","entropy_metric_scaled":"0","epoch_duration_secs":"2592000","epoch_number":"0","governance_root":"0000000000000000000000000000000000000000000000000000000000000000","impact_pool_root":"0000000000000000000000000000000000000000000000000000000000000000","kernel_hash":"0000000000000000000000000000000000000000000000000000000000000000","previous_root":"0000000000000000000000000000000000000000000000000000000000000000","validator_set_root":"0000000000000000000000000000000000000000000000000000000000000000","vdf_challenge_seed":"0000000000000000000000000000000000000000000000000000000000000000"}"#;
         assert_eq!(&bytes, expected,
             "canonical bytes diverged from expected — this is a serialization fork");
     }
@@ -287,7 +568,9 @@ mod tests {
         let a = EpochState {
             bond_pool_root:        [0u8; 32],
             entropy_metric_scaled: 0,
+            epoch_duration_secs:   DEFAULT_EPOCH_DURATION_SECS,
             epoch_number:          0,
+            governance_root:       [0u8; 32],
             impact_pool_root:      [0u8; 32],
             kernel_hash:           [0u8; 32],
             previous_root:         [0u8; 32],
@@ -306,7 +589,9 @@ mod tests {
         let base = EpochState {
             bond_pool_root:        [0u8; 32],
             entropy_metric_scaled: 0,
+            epoch_duration_secs:   DEFAULT_EPOCH_DURATION_SECS,
             epoch_number:          0,
+            governance_root:       [0u8; 32],
             impact_pool_root:      [0u8; 32],
             kernel_hash:           [0u8; 32],
             previous_root:         [0u8; 32],
@@ -319,6 +604,31 @@ mod tests {
 
         let modified = EpochState { entropy_metric_scaled: 943_932_824_245, ..base.clone() };
         assert_ne!(base.canonical_bytes().unwrap(), modified.canonical_bytes().unwrap());
+
+        let modified = EpochState { epoch_duration_secs: 3_600, ..base.clone() };
+        assert_ne!(base.canonical_bytes().unwrap(), modified.canonical_bytes().unwrap());
+
+        let modified = EpochState { governance_root: [1u8; 32], ..base.clone() };
+        assert_ne!(base.canonical_bytes().unwrap(), modified.canonical_bytes().unwrap());
+    }
+
+    #[test]
+    fn governance_parameters_change_the_state_root() {
+        use crate::state::governance::GovernanceParams;
+
+        let a = GovernanceParams::v0(10);
+        let b = GovernanceParams { emission_rate_bps: 25, ..a };
+        let (root_a, root_b) = (a.root().unwrap(), b.root().unwrap());
+        assert_ne!(root_a, root_b);
+
+        let state = |governance_root| EpochState { governance_root, ..EpochState::genesis().unwrap() }.commit().unwrap();
+        let (sa, sb) = (state(root_a), state(root_b));
+        assert_ne!(sa.state_root, sb.state_root);
+
+        let hex: String = root_a.iter().map(|b| format!("{:02x}", b)).collect();
+        let bytes = sa.canonical_bytes().unwrap();
+        let needle = format!(r#""governance_root":"{}""#, hex);
+        assert!(bytes.windows(needle.len()).any(|w| w == needle.as_bytes()));
     }
 
     // ── Pinned constitutional hash vector ─────────────────────────────────────
@@ -332,7 +642,9 @@ mod tests {
         let s = EpochState {
             bond_pool_root:        [0u8; 32],
             entropy_metric_scaled: 0,
+            epoch_duration_secs:   DEFAULT_EPOCH_DURATION_SECS,
             epoch_number:          0,
+            governance_root:       [0u8; 32],
             impact_pool_root:      [0u8; 32],
             kernel_hash:           [0u8; 32],
             previous_root:         [0u8; 32],
@@ -345,16 +657,32 @@ mod tests {
         // SHA-256(canonical JSON of all-zero genesis EpochState)
         // Changing ANY field name, order, or encoding rule breaks this assertion.
         let expected: [u8; 32] = [
-            0xbb, 0x44, 0xf7, 0xd8, 0x3e, 0x9e, 0x4e, 0x42,
-            0x68, 0x09, 0xa8, 0x1b, 0x66, 0xf7, 0x2a, 0x49,
-            0x44, 0x32, 0x95, 0x4f, 0xbc, 0x05, 0xbf, 0x8f,
-            0x07, 0x89, 0xa6, 0x23, 0xb1, 0xd5, 0xad, 0xe1,
+            0x59, 0x7e, 0x8d, 0xe1, 0x29, 0x38, 0x4b, 0x57,
+            0x86, 0xee, 0xf8, 0x05, 0x9c, 0xb3, 0x6a, 0xf4,
+            0x0e, 0xe3, 0x9b, 0xb9, 0x11, 0x9e, 0x9c, 0x81,
+            0x4b, 0xd0, 0xf7, 0x0d, 0xa3, 0xbf, 0xb7, 0xfa,
         ];
         assert_eq!(root, expected, "genesis state_root diverged — serialization format changed");
         // Verify stability: compute twice, must be identical.
         assert_eq!(root, s.compute_state_root().unwrap(), "state_root must be deterministic");
     }
 
+    #[test]
+    fn genesis_constructor_reproduces_pinned_root() {
+        // PINNED CONSTITUTIONAL VECTOR — DO NOT CHANGE.
+        // The all-zero state above with previous_root = GENESIS_PREVIOUS_ROOT.
+        // genesis() must land here — never on an all-zero fallback.
+        let g = EpochState::genesis().unwrap();
+        let expected: [u8; 32] = [
+            0xf9, 0xb2, 0x41, 0x14, 0xda, 0xe6, 0xa3, 0x52,
+            0x83, 0x95, 0xd5, 0x5d, 0xf4, 0x31, 0xb4, 0xcb,
+            0x72, 0x2a, 0x47, 0xe8, 0x11, 0xb9, 0x8e, 0x23,
+            0x03, 0xac, 0xbb, 0xb6, 0xaa, 0x27, 0xd7, 0xbb,
+        ];
+        assert_eq!(g.state_root, expected, "genesis() state_root diverged from pinned vector");
+        assert_eq!(g.state_root, g.compute_state_root().unwrap());
+    }
+
     // ── commit() API ──────────────────────────────────────────────────────────
 
     #[test]
@@ -362,7 +690,9 @@ mod tests {
         let uncommitted = EpochState {
             bond_pool_root:        [0u8; 32],
             entropy_metric_scaled: 0,
+            epoch_duration_secs:   DEFAULT_EPOCH_DURATION_SECS,
             epoch_number:          1,
+            governance_root:       [0u8; 32],
             impact_pool_root:      [0u8; 32],
             kernel_hash:           [0u8; 32],
             previous_root:         [0u8; 32],
@@ -377,6 +707,73 @@ mod tests {
             "committed state_root must not be all zeros");
     }
 
+    #[test]
+    fn with_roots_matches_manual_set_then_commit() {
+        let (v, i, b) = (sha256(b"validators"), sha256(b"impacts"), sha256(b"bonds"));
+        let mut manual = EpochState::genesis().unwrap();
+        manual.validator_set_root = v;
+        manual.impact_pool_root   = i;
+        manual.bond_pool_root     = b;
+        let manual = manual.commit().unwrap();
+
+        let built = EpochState::genesis().unwrap().with_roots(v, i, b).unwrap();
+        assert_eq!(built, manual);
+        assert_eq!(built.state_root, built.compute_state_root().unwrap());
+    }
+
+    #[test]
+    fn eq_precommit_ignores_only_state_root() {
+        let mut a = EpochState::genesis().unwrap();
+        a.epoch_number = 1;
+        let mut b = a.clone();
+        a.state_root = [0u8; 32];
+        b.state_root = [0xFF; 32];
+        assert!(a.eq_precommit(&b));
+        assert_ne!(a, b);
+
+        b.entropy_metric_scaled += 1;
+        assert!(!a.eq_precommit(&b));
+    }
+
+    #[test]
+    fn epoch_id_is_number_and_root_prefix() {
+        let genesis = EpochState::genesis().unwrap();
+        assert_eq!(genesis.epoch_id(), "epoch-0-f9b24114");
+        assert_eq!(genesis.epoch_id(), genesis.clone().epoch_id());
+
+        let next = crate::transition::apply_epoch_dry_run(&genesis, 0, [0u8; 32]).unwrap();
+        assert_eq!(next.epoch_id().len(), "epoch-1-".len() + 8);
+        assert!(next.epoch_id().starts_with("epoch-1-"));
+        let mut other = genesis.clone();
+        other.state_root[0] ^= 0xFF;
+        assert_ne!(other.epoch_id(), genesis.epoch_id());
+    }
+
+    #[test]
+    fn header_hash_does_not_commit_to_pools() {
+        let a = EpochState::genesis().unwrap();
+        // Same header fields, different pool roots (state_root deliberately not recomputed).
+        let mut b = a.clone();
+        b.validator_set_root = sha256(b"validators");
+        b.bond_pool_root     = sha256(b"bonds");
+        assert_eq!(a.header(), b.header());
+        assert_eq!(a.header().header_hash(), b.header().header_hash());
+
+        // Each header field is committed.
+        let base = a.header().header_hash();
+        let mut h = a.header();
+        h.epoch_number = 1;
+        assert_ne!(h.header_hash(), base);
+        let mut h = a.header();
+        h.previous_root = [1u8; 32];
+        assert_ne!(h.header_hash(), base);
+        let mut h = a.header();
+        h.kernel_hash = [1u8; 32];
+        assert_ne!(h.header_hash(), base);
+        let b = b.commit().unwrap();
+        assert_ne!(b.header().header_hash(), base);
+    }
+
     // ── encode helpers ────────────────────────────────────────────────────────
 
     #[test]
@@ -409,4 +806,130 @@ mod tests {
         assert_eq!(s.len(), 64);
         assert!(s.iter().all(|&b| b == b'f'));
     }
+
+    // ── epoch_signing_message ─────────────────────────────────────────────────
+
+    fn empty_bundle() -> StateWitnessBundle {
+        use crate::state::witness::EntropyStats;
+        StateWitnessBundle {
+            bond_witnesses: vec![],
+            entropy_stats: EntropyStats {
+                active_bonded_magnitude_raw: 0,
+                total_supply_raw: 1000,
+                unique_active_validators: 1,
+                optimal_validator_count: 3,
+            },
+            impact_witnesses: vec![],
+            mutation_authors: vec![],
+            validator_signatures: vec![],
+            validator_witnesses: vec![],
+            vdf_proof: None,
+        }
+    }
+
+    #[test]
+    fn signing_message_matches_two_step_computation() {
+        let prev = EpochState::genesis().unwrap();
+        let witness = empty_bundle();
+        let kernel_hash = [0x42u8; 32];
+        let bundle_hash = compute_bundle_hash(&witness);
+        let manual = compute_epoch_signing_root(&prev.state_root, &bundle_hash, 1, &kernel_hash, prev.epoch_duration_secs);
+        assert_eq!(epoch_signing_message(&prev, &witness, &kernel_hash).unwrap(), manual);
+    }
+
+    #[test]
+    fn signing_message_rejects_epoch_overflow() {
+        let prev = EpochState { epoch_number: u64::MAX, ..EpochState::genesis().unwrap() };
+        assert_eq!(
+            epoch_signing_message(&prev, &empty_bundle(), &[0u8; 32]),
+            Err(TransitionError::MathOverflow)
+        );
+    }
+
+    // ── Progress invariant ────────────────────────────────────────────────────
+
+    #[test]
+    fn normal_epoch_makes_progress() {
+        let genesis = EpochState::genesis().unwrap();
+        let next = EpochState {
+            epoch_number:  1,
+            previous_root: genesis.state_root,
+            ..genesis.clone()
+        }.commit().unwrap();
+        assert_eq!(next.verify_progress(), Ok(()));
+    }
+
+    #[test]
+    fn equal_roots_after_genesis_are_rejected() {
+        let mut stalled = EpochState::genesis().unwrap();
+        stalled.epoch_number = 5;
+        stalled.previous_root = stalled.state_root;
+        assert_eq!(stalled.verify_progress(), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn genesis_sentinel_is_sha256_of_tag() {
+        assert_eq!(GENESIS_PREVIOUS_ROOT, sha256(b"GENESIS"));
+        assert_ne!(GENESIS_PREVIOUS_ROOT, [0u8; 32]);
+        assert!(EpochState::genesis().unwrap().is_genesis());
+    }
+
+    #[test]
+    fn zeroed_previous_root_after_genesis_is_rejected() {
+        let genesis = EpochState::genesis().unwrap();
+        let unchained = EpochState { epoch_number: 1, previous_root: [0u8; 32], ..genesis.clone() }.commit().unwrap();
+        assert!(!unchained.is_genesis());
+        assert_eq!(unchained.verify_progress(), Err(TransitionError::InvalidSerialization));
+
+        // An epoch-0 state with a zeroed link is not genesis either.
+        let zeroed = EpochState { previous_root: [0u8; 32], ..genesis }.commit().unwrap();
+        assert!(!zeroed.is_genesis());
+    }
+
+    #[test]
+    fn genesis_is_exempt_from_progress_check() {
+        let genesis = EpochState::genesis().unwrap();
+        assert_eq!(genesis.verify_progress(), Ok(()));
+        // Even a degenerate genesis whose sentinel equals its own root passes.
+        let mut degenerate = genesis.clone();
+        degenerate.previous_root = degenerate.state_root;
+        assert_eq!(degenerate.verify_progress(), Ok(()));
+    }
+
+    #[test]
+    fn next_entropy_stats_follows_minted_supply() {
+        use crate::state::witness::WorkMeter;
+        use crate::transition::PoolChanges;
+
+        let prev_stats = EntropyStats {
+            active_bonded_magnitude_raw: 500,
+            total_supply_raw:            1_000,
+            unique_active_validators:    2,
+            optimal_validator_count:     3,
+        };
+        let receipt = |supply: SupplyDeltas| TransitionReceipt {
+            state: EpochState::genesis().unwrap(),
+            work: WorkMeter::default(),
+            pools: PoolChanges::default(),
+            is_vacuous: true,
+            debug_canonical_bytes: None,
+            trace: Vec::new(),
+            supply,
+        };
+        assert_eq!(next_entropy_stats(&prev_stats, &receipt(SupplyDeltas::default())), Ok(prev_stats));
+
+        // Mint 250: supply grows by exactly that, bonded stake does not.
+        let minted = receipt(SupplyDeltas { minted_raw: 250, ..SupplyDeltas::default() });
+        let next = next_entropy_stats(&prev_stats, &minted).unwrap();
+        assert_eq!(next.total_supply_raw, 1_250);
+        assert_eq!(next.active_bonded_magnitude_raw, 500);
+        assert_eq!(next.validate(), Ok(()));
+
+        // Slashing lowers both; unbonding more than is bonded underflows.
+        let slashed = receipt(SupplyDeltas { slashed_raw: 100, ..SupplyDeltas::default() });
+        let next = next_entropy_stats(&prev_stats, &slashed).unwrap();
+        assert_eq!((next.total_supply_raw, next.active_bonded_magnitude_raw), (900, 400));
+        let unbonded = receipt(SupplyDeltas { unbonded_raw: 501, ..SupplyDeltas::default() });
+        assert_eq!(next_entropy_stats(&prev_stats, &unbonded), Err(TransitionError::MathUnderflow));
+    }
 }
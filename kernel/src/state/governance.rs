@@ -0,0 +1,98 @@
+//! `GovernanceParams` — the protocol parameter set committed by `EpochState.governance_root`.
+//!
+//! # Commitment (Frozen)
+//!
+//! ```text
+//! leaf_i          = canonical JSON {"name": <parameter>, "value": <decimal string>}
+//! governance_root = compute_merkle_root([leaf_i sorted by bytes])
+//! ```
+//!
+//! Every leaf starts with `{"name":"`, so byte order is parameter-name order:
+//! `decay_factor_scaled`, `emission_rate_bps`, `optimal_validator_count`.
+//! Adding a parameter adds a leaf and changes every root: a hard fork.
+
+use crate::math::fixed::Fixed;
+use crate::physics::canonical_json::CanonicalObject;
+use crate::physics::hashing::Digest;
+use crate::physics::merkle::compute_merkle_root;
+use crate::state::decay::DECAY_FACTOR_SCALED;
+use crate::TransitionError;
+
+/// The active protocol parameters. Changing any of them changes `root()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GovernanceParams {
+    /// Per-epoch decay multiplier, raw `Fixed` (see `state::decay`).
+    pub decay_factor_scaled: u128,
+    /// Emission rate in basis points of supply per epoch. 0 under `ZeroEmission`.
+    pub emission_rate_bps: u32,
+    /// The genesis-manifest validator target. Committed here only: no kernel
+    /// path checks `EntropyStats.optimal_validator_count` against it in v0.0.2.
+    pub optimal_validator_count: u64,
+}
+
+impl GovernanceParams {
+    /// The v0.0.2 parameters: constitutional decay, no emission, `optimal_validator_count` as given.
+    pub fn v0(optimal_validator_count: u64) -> Self {
+        GovernanceParams {
+            decay_factor_scaled: DECAY_FACTOR_SCALED,
+            emission_rate_bps: 0,
+            optimal_validator_count,
+        }
+    }
+
+    /// Merkle root of the parameter set (see module doc for the leaf format).
+    ///
+    /// Returns `MathOverflow` if `decay_factor_scaled` is not a valid `Fixed`.
+    pub fn root(&self) -> Result<Digest, TransitionError> {
+        Fixed::from_raw(self.decay_factor_scaled)?;
+        let leaf = |name: &str, value: u128| {
+            CanonicalObject::new()
+                .string("name", name.as_bytes())
+                .string("value", value.to_string().as_bytes())
+                .finish()
+        };
+        let leaves = [
+            leaf("decay_factor_scaled", self.decay_factor_scaled)?,
+            leaf("emission_rate_bps", self.emission_rate_bps as u128)?,
+            leaf("optimal_validator_count", self.optimal_validator_count as u128)?,
+        ];
+        debug_assert!(leaves.windows(2).all(|w| w[0] < w[1]));
+        compute_merkle_root(&leaves)
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::hashing::{hash_leaf, hash_node};
+
+    #[test]
+    fn root_matches_hand_built_tree() {
+        let p = GovernanceParams::v0(10);
+        let l0 = hash_leaf(br#"{"name":"decay_factor_scaled","value":"943932824245"}"#);
+        let l1 = hash_leaf(br#"{"name":"emission_rate_bps","value":"0"}"#);
+        let l2 = hash_leaf(br#"{"name":"optimal_validator_count","value":"10"}"#);
+        // Three leaves pad to four by duplicating the last.
+        assert_eq!(p.root(), Ok(hash_node(&hash_node(&l0, &l1), &hash_node(&l2, &l2))));
+    }
+
+    #[test]
+    fn every_parameter_is_committed() {
+        let base = GovernanceParams::v0(10);
+        let roots = [
+            base,
+            GovernanceParams { decay_factor_scaled: base.decay_factor_scaled - 1, ..base },
+            GovernanceParams { emission_rate_bps: 50, ..base },
+            GovernanceParams { optimal_validator_count: 11, ..base },
+        ]
+        .map(|p| p.root().unwrap());
+        for i in 0..roots.len() {
+            for j in i + 1..roots.len() {
+                assert_ne!(roots[i], roots[j]);
+            }
+        }
+    }
+}
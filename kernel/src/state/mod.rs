@@ -1,5 +1,8 @@
-//! State module: EpochState struct, decay logic, entropy computation.
+//! State module: EpochState struct, governance parameters, decay logic, entropy computation, sortition.
 pub mod decay;
 pub mod entropy;
+pub mod entropy_experimental;
 pub mod epoch;
+pub mod governance;
+pub mod sortition;
 pub mod witness;
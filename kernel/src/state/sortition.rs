@@ -0,0 +1,120 @@
+//! Weighted validator sortition over the VDF challenge seed.
+//!
+//! CONSTITUTIONAL RULES:
+//! - The only randomness is `vdf_challenge_seed`. No RNG, no clock, no host input.
+//! - Integer comparisons only: the draw is scaled with `checked_mul_div_raw`, never floats.
+//!
+//! # Algorithm (Frozen)
+//!
+//! ```text
+//! draw     = first 8 bytes of SHA256(seed || candidate), big-endian u64
+//! point    = floor(draw * total_weight / 2^64)          ∈ [0, total_weight)
+//! selected = point < candidate_weight
+//! ```
+//!
+//! Each candidate is selected independently with probability
+//! `candidate_weight / total_weight` (to within 2^-64). With the all-zero stub
+//! seed, selection is a fixed function of the candidate key.
+
+use crate::math::overflow::checked_mul_div_raw;
+use crate::physics::hashing::{Digest, Sha256};
+use crate::TransitionError;
+
+/// Decide whether `candidate` is selected for the next epoch.
+///
+/// Returns `DivisionByZero` if `total_weight` is zero and `MathUnderflow` if
+/// `candidate_weight > total_weight` (a candidate cannot hold more than the total).
+pub fn sortition(
+    seed:             &Digest,
+    candidate:        &[u8; 32],
+    total_weight:     u128,
+    candidate_weight: u128,
+) -> Result<bool, TransitionError> {
+    if total_weight == 0 {
+        return Err(TransitionError::DivisionByZero);
+    }
+    if candidate_weight > total_weight {
+        return Err(TransitionError::MathUnderflow);
+    }
+
+    let digest = Sha256::new().update_digest(seed).update(candidate).finalize();
+    let mut draw_bytes = [0u8; 8];
+    draw_bytes.copy_from_slice(&digest[..8]);
+    let draw = u64::from_be_bytes(draw_bytes) as u128;
+
+    // draw < 2^64, so point < total_weight and never overflows.
+    let point = checked_mul_div_raw(draw, total_weight, 1u128 << 64)?;
+    Ok(point < candidate_weight)
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Tests
+// ──────────────────────────────────────────────────────────────────────────────
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::hashing::sha256;
+
+    fn candidate(i: u32) -> [u8; 32] {
+        sha256(&i.to_be_bytes())
+    }
+
+    #[test]
+    fn selection_is_stable_for_fixed_inputs() {
+        let seed = [0u8; 32];
+        for i in 0..64 {
+            let c = candidate(i);
+            let first = sortition(&seed, &c, 1_000, 500).unwrap();
+            assert_eq!(sortition(&seed, &c, 1_000, 500).unwrap(), first);
+        }
+
+        // PINNED (cross-checked with an independent SHA-256). DO NOT CHANGE.
+        let first_16: Vec<bool> = (0..16).map(|i| sortition(&seed, &candidate(i), 1_000, 500).unwrap()).collect();
+        let expected = [1, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1].map(|b| b == 1);
+        assert_eq!(first_16, expected);
+        let count = (0..64).filter(|&i| sortition(&seed, &candidate(i), 1_000, 500).unwrap()).count();
+        assert_eq!(count, 28);
+    }
+
+    #[test]
+    fn zero_and_full_weight_are_certain() {
+        let seed = sha256(b"seed");
+        for i in 0..64 {
+            let c = candidate(i);
+            assert!(!sortition(&seed, &c, 1_000, 0).unwrap());
+            assert!(sortition(&seed, &c, 1_000, 1_000).unwrap());
+        }
+    }
+
+    #[test]
+    fn selection_is_roughly_proportional_to_weight() {
+        // 4 000 candidates at each weight share; expect share ± 3 percentage points.
+        let seed = sha256(b"epoch 7");
+        let total = 1_000_000_000_000_000u128; // 1 000 units at SCALE = 10^12
+        for percent in [10u128, 25, 50, 90] {
+            let weight = total / 100 * percent;
+            let selected = (0..4_000)
+                .filter(|&i| sortition(&seed, &candidate(i), total, weight).unwrap())
+                .count() as u128;
+            let expected = 4_000 * percent / 100;
+            assert!(
+                selected.abs_diff(expected) <= 120,
+                "{}% weight: selected {} of 4000, expected ≈ {}", percent, selected, expected
+            );
+        }
+    }
+
+    #[test]
+    fn seed_changes_the_selection() {
+        let a = (0..256).map(|i| sortition(&[0u8; 32], &candidate(i), 2, 1).unwrap());
+        let b = (0..256).map(|i| sortition(&[1u8; 32], &candidate(i), 2, 1).unwrap());
+        assert!(a.zip(b).any(|(x, y)| x != y));
+    }
+
+    #[test]
+    fn invalid_weights_are_rejected() {
+        let c = candidate(0);
+        assert_eq!(sortition(&[0u8; 32], &c, 0, 0), Err(TransitionError::DivisionByZero));
+        assert_eq!(sortition(&[0u8; 32], &c, 10, 11), Err(TransitionError::MathUnderflow));
+    }
+}
@@ -24,9 +24,57 @@
 //!   by the preceding mutation's `reconstruct_root()`.
 //! - The host is responsible for constructing paths relative to intermediate
 //!   roots. Model B (paths relative to original root) is rejected.
+//!
+//! # INSERT Forms
+//!
+//! An INSERT (`old_value == []`) is accepted in one of two forms:
+//! - **Slot form:** the path, walked from `hash_leaf([])`, reproduces the
+//!   current root. This is the `witness_schema.md` rule, and it is how the
+//!   first leaf enters an empty pool (`hash_leaf([]) == empty_tree_root()`).
+//! - **Frontier form:** the path is the authentication path of the new leaf as
+//!   the LAST leaf of the grown tree, exactly as `compute_merkle_root` would
+//!   build it. Padding siblings to the right of the new leaf must be the
+//!   duplicated new leaf. The kernel re-derives the pre-insert root from the
+//!   path and requires it to equal the current root. For an append at index
+//!   n ≥ 2, `LeafMutation.append_proof` must also authenticate leaf n − 2 of
+//!   the prior tree and show it differs from leaf n − 1, which proves the
+//!   prior tree ended at n and not earlier with padding copies in its place.
+//!
+//! # DELETE Semantics
+//!
+//! A DELETE (`new_value == []`) writes a tombstone: the leaf becomes
+//! `hash_leaf([])`, the same empty slot a slot-form INSERT fills. The tree keeps
+//! its shape and leaf count — nothing is removed. Tombstones are only meaningful
+//! in the validator pool (deregistration); impact and bond records are immutable,
+//! so `apply_epoch` rejects DELETEs there via `reject_deletions`.
+//!
+//! A DELETE must prove the exact value it removes: `old_value` must be non-empty
+//! and `hash_leaf(old_value)` must authenticate in slot form against the current
+//! intermediate root. Deleting an empty slot (`[] → []`) is rejected, and the
+//! frontier form never applies — a DELETE cannot append.
+//!
+//! The frontier form exists because duplication padding means a tree of n
+//! leaves has no empty slot for leaf n+1: growing 1→2 or 2→3 leaves changes
+//! the depth or the padding, which no single slot path can express.
+//! At an even index that is not a power of two, the prior padding duplicates
+//! a leaf buried inside a sibling hash; the append proof supplies it.
+//!
+//! Neither form can overwrite a leaf: the slot form needs the slot to hash to
+//! `hash_leaf([])`, and the frontier form re-derives the whole prior tree.
+//! The one ambiguity is inherent to duplication padding: a tree whose last
+//! real leaves are equal has the same root as the shorter tree padded with
+//! that leaf, so pool values must be unique for leaf counts to be unambiguous.
+//! The append proof relies on this: a pool whose last two real leaves are
+//! equal cannot be appended to in frontier form.
+//!
+//! Neither form sees leaf CONTENT beyond the new value, so neither can keep the
+//! pool sorted. `apply_pool_mutations_sorted` adds that check using
+//! host-supplied, authenticated copies of the leaves next to each INSERT.
 
 use crate::TransitionError;
-use crate::physics::hashing::{Digest, sha256, hash_leaf, hash_node};
+use crate::physics::hashing::{
+    ct_eq, Digest, sha256, hash_leaf, hash_node, from_hex, to_hex, Sha256,
+};
 use crate::physics::merkle::MAX_MERKLE_DEPTH;
 use crate::state::epoch::MAX_PAYLOADS_PER_EPOCH;
 
@@ -51,6 +99,8 @@ pub const MAX_VALUE_BYTES: usize = 4096;
 /// `Left`  → current is left child  → `parent = hash_node(current, sibling)`
 /// `Right` → current is right child → `parent = hash_node(sibling, current)`
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum NodePosition {
     Left,
     Right,
@@ -62,8 +112,10 @@ pub enum NodePosition {
 
 /// One level in a Merkle authentication path.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MerklePathNode {
     /// The sibling's SHA-256 hash at this level.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub sibling: Digest,
     /// Which side the CURRENT node occupies at this level.
     pub position: NodePosition,
@@ -100,7 +152,7 @@ impl MerklePath {
         leaf_hash: Digest,
         expected_root: Digest,
     ) -> Result<(), TransitionError> {
-        if self.walk(leaf_hash) != expected_root {
+        if !ct_eq(&self.walk(leaf_hash), &expected_root) {
             Err(TransitionError::InvalidMerkleWitness)
         } else {
             Ok(())
@@ -116,6 +168,117 @@ impl MerklePath {
         self.walk(new_leaf_hash)
     }
 
+    /// Derive the root of the tree BEFORE `new_leaf_hash` was appended, treating
+    /// this path as the frontier path of the new last leaf (see module doc).
+    ///
+    /// Returns `None` if the path is not a well-formed frontier path, if the
+    /// prior root cannot be derived from the path alone, or if `proof` does not
+    /// show the prior tree ended where the new leaf begins.
+    fn prior_root_before_append(
+        &self,
+        new_leaf_hash: Digest,
+        proof: Option<&AppendProof>,
+        meter: &mut WorkMeter,
+    ) -> Option<Digest> {
+        let top = self.nodes.last()?;
+        // The new leaf must sit in the right half of the tree, otherwise the
+        // tree would be deeper than its padded leaf count requires.
+        if top.position != NodePosition::Right {
+            return None;
+        }
+
+        // Every LEFT level must carry padding: a full subtree of duplicates of
+        // the new leaf. Anything else would smuggle extra leaves past the end.
+        let mut pad = new_leaf_hash;
+        for node in &self.nodes {
+            if node.position == NodePosition::Left && node.sibling != pad {
+                return None;
+            }
+            pad = hash_node(&pad, &pad);
+            meter.sha256_invocations += 1;
+        }
+
+        let below_top = &self.nodes[..self.nodes.len() - 1];
+        if below_top.iter().all(|n| n.position == NodePosition::Left) {
+            // Index is a power of two: the prior tree is exactly the left
+            // sibling at the top level, provided it was complete.
+            return self.prior_tree_is_complete(top.sibling, proof, meter).then_some(top.sibling);
+        }
+
+        // The prior tree padded every slot from the new leaf's index rightwards
+        // with copies of its last leaf n − 1. At an odd index that leaf is the
+        // level-0 sibling; at an even index it is hidden inside a sibling hash,
+        // so it is taken from the append proof, whose level-0 sibling is leaf
+        // n − 1. Either way `prior_tree_is_complete` then authenticates it
+        // against the derived root.
+        // A left-hand subtree made entirely of copies of `last` is itself
+        // padding: accepting it would turn padding into phantom real leaves
+        // (e.g. appending at index 7 of a 5-leaf tree).
+        let last = match self.nodes[0].position {
+            NodePosition::Right => self.nodes[0].sibling,
+            NodePosition::Left  => proof?.path.nodes.first()?.sibling,
+        };
+        let mut current = last;
+        let mut old_pad = last;
+        for (level, node) in self.nodes.iter().enumerate() {
+            if level > 0 && node.position == NodePosition::Right && node.sibling == old_pad {
+                return None;
+            }
+            current = match node.position {
+                NodePosition::Left  => hash_node(&current, &old_pad),
+                NodePosition::Right => hash_node(&node.sibling, &current),
+            };
+            old_pad = hash_node(&old_pad, &old_pad);
+            meter.sha256_invocations += 2;
+            meter.merkle_steps += 1;
+        }
+        self.prior_tree_is_complete(current, proof, meter).then_some(current)
+    }
+
+    /// Check that the tree under `prior_root` held exactly `n = leaf_index()`
+    /// real leaves, so the append lands after its last REAL leaf and not after
+    /// padding (e.g. appending at index 4 of `[a, b, c]` would otherwise commit
+    /// the padding copy of `c` as a phantom leaf 3).
+    ///
+    /// For `n ≥ 2`, `proof` must authenticate leaf `n − 2` against `prior_root`
+    /// at the prior depth, and that leaf must differ from leaf `n − 1`. Under
+    /// padding, every leaf from the last real one onwards is the same copy, so
+    /// two different leaves at `n − 2` and `n − 1` put the end of the tree at
+    /// `n`. Leaf `n − 1` is the level-0 sibling of the proof path when `n` is
+    /// even, and of this path when `n` is odd.
+    fn prior_tree_is_complete(
+        &self,
+        prior_root: Digest,
+        proof: Option<&AppendProof>,
+        meter: &mut WorkMeter,
+    ) -> bool {
+        let n = self.leaf_index();
+        if n < 2 {
+            return true;
+        }
+        let Some(proof) = proof else { return false };
+        let Some(first) = proof.path.nodes.first() else { return false };
+        let prior_depth = if n.is_power_of_two() { self.nodes.len() - 1 } else { self.nodes.len() };
+        let last = if n.is_multiple_of(2) { first.sibling } else { self.nodes[0].sibling };
+
+        meter.record_walk(&proof.path);
+        proof.path.nodes.len() == prior_depth
+            && proof.path.leaf_index() == n - 2
+            && proof.leaf_hash != last
+            && ct_eq(&proof.path.walk(proof.leaf_hash), &prior_root)
+    }
+
+    /// Leaf index this path authenticates: bit `i` is set when the current node
+    /// is the RIGHT child at level `i`. Exact for depth ≤ 64 (every kernel path
+    /// is ≤ 40); a hand-built deeper path drops the levels past bit 63.
+    pub fn leaf_index(&self) -> u64 {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.position == NodePosition::Right)
+            .fold(0u64, |acc, (level, _)| acc | 1u64.checked_shl(level as u32).unwrap_or(0))
+    }
+
     /// Internal: walk the path from `start` to the root using stored siblings.
     fn walk(&self, start: Digest) -> Digest {
         let mut current = start;
@@ -139,28 +302,54 @@ impl MerklePath {
 ///
 /// Field names and size limits from `witness_schema.md §Struct Layout`.
 /// On-wire, `key` must be the canonical JCS-encoded identifier for this entry.
-/// The kernel extracts the key field from `old_value` and asserts it matches
-/// `LeafMutation.key` before accepting the path (Gap 1 invariant).
+/// The schema's Gap 1 rule (extract the key field from `old_value` and match it
+/// against `key`) is NOT enforced in v0.0.2: the kernel does not parse leaf
+/// values, so `key` is bound to the leaf only through the signed bundle.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LeafMutation {
     /// Canonical identifier for this leaf (JCS-encoded key, ≤ MAX_KEY_BYTES).
     /// For validator set: lowercase hex of Ed25519 public key.
     /// For impact pool: lowercase hex of SHA-256 of ProofOfImpact canonical bytes.
     /// For bond pool: lowercase hex of SHA-256 of VouchBond canonical bytes.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_bytes"))]
     pub key: Vec<u8>,
 
     /// Canonical bytes of the leaf value BEFORE this mutation.
     /// Empty (`[]`) means this is an INSERT (leaf did not previously exist).
     /// In that case: `hash_leaf([]) == empty_tree_root()` — both equal SHA256([0x00]).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_bytes"))]
     pub old_value: Vec<u8>,
 
     /// Canonical bytes of the leaf value AFTER this mutation.
     /// Empty (`[]`) means this is a DELETE (validator withdrawal only in v0.0.2).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_bytes"))]
     pub new_value: Vec<u8>,
 
     /// Authentication path for this leaf, relative to the EVOLVING pool root
     /// (Model A). The host constructs this path accounting for all prior
     /// mutations that have already been applied to this pool in this epoch.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::merkle_path"))]
+    pub path: MerklePath,
+
+    /// Frontier-form INSERTs at leaf index n ≥ 2 only: proof that the prior
+    /// tree held exactly n leaves (see module doc). `None` for every other
+    /// mutation; ignored unless the frontier form is used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub append_proof: Option<AppendProof>,
+}
+
+/// Authentication of the prior tree's second-to-last leaf, carried by a
+/// frontier-form INSERT so the kernel can tell the last real leaf from padding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppendProof {
+    /// Leaf hash of leaf `n − 2` of the pre-append tree, where `n` is the
+    /// index the new leaf is appended at.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
+    pub leaf_hash: Digest,
+    /// Authentication path of that leaf against the pre-append root.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::merkle_path"))]
     pub path: MerklePath,
 }
 
@@ -195,23 +384,29 @@ impl LeafMutation {
 /// The kernel verifies: `active_bonded_magnitude_raw ≤ total_supply_raw`
 /// and `optimal_validator_count > 0`. All other values are host-trusted.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntropyStats {
     /// Sum of all active VouchBond magnitudes this epoch (raw `Fixed` inner u128).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::decimal"))]
     pub active_bonded_magnitude_raw: u128,
     /// Total circulating supply at epoch start (raw `Fixed` inner u128).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::decimal"))]
     pub total_supply_raw: u128,
     /// Count of unique validators that submitted ≥ 1 payload this epoch.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::decimal"))]
     pub unique_active_validators: u64,
     /// Target validator set size from the Genesis Manifest (must be > 0).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::decimal"))]
     pub optimal_validator_count: u64,
 }
 
 impl EntropyStats {
     /// Validate the internally-checkable constraints.
     pub fn validate(&self) -> Result<(), TransitionError> {
-        // Bonded amount cannot exceed total supply.
+        // Bonded amount cannot exceed total supply: the unbonded remainder
+        // (supply − bonded) would go below zero.
         if self.active_bonded_magnitude_raw > self.total_supply_raw {
-            return Err(TransitionError::MathOverflow);
+            return Err(TransitionError::MathUnderflow);
         }
         // Optimal count of zero would cause DivisionByZero in entropy computation.
         if self.optimal_validator_count == 0 {
@@ -234,13 +429,83 @@ pub const MAX_VALIDATOR_SIGNATURES: usize = MAX_PAYLOADS_PER_EPOCH;
 /// Within `StateWitnessBundle.validator_signatures`, entries MUST be in strictly
 /// ascending order of `validator_pubkey`. No duplicate pubkeys are permitted.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidatorSignature {
     /// Ed25519 public key (32 bytes, compressed Edwards y-coordinate + sign bit).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub validator_pubkey: [u8; 32],
     /// Ed25519 signature (64 bytes: R || s).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_array"))]
     pub signature: [u8; 64],
 }
 
+/// Canonical wire length of one `ValidatorSignature`: pubkey (32) || signature (64).
+pub const VALIDATOR_SIGNATURE_BYTES: usize = 96;
+
+impl ValidatorSignature {
+    /// Canonical 96-byte wire form: `validator_pubkey || signature`.
+    pub fn to_bytes(&self) -> [u8; VALIDATOR_SIGNATURE_BYTES] {
+        let mut out = [0u8; VALIDATOR_SIGNATURE_BYTES];
+        out[..32].copy_from_slice(&self.validator_pubkey);
+        out[32..].copy_from_slice(&self.signature);
+        out
+    }
+
+    /// Inverse of `to_bytes`. Infallible: every 96-byte string splits into a
+    /// pubkey and a signature. Cryptographic validity is checked by `verify_quorum`.
+    pub fn from_bytes(bytes: &[u8; VALIDATOR_SIGNATURE_BYTES]) -> ValidatorSignature {
+        let mut validator_pubkey = [0u8; 32];
+        let mut signature = [0u8; 64];
+        validator_pubkey.copy_from_slice(&bytes[..32]);
+        signature.copy_from_slice(&bytes[32..]);
+        ValidatorSignature { validator_pubkey, signature }
+    }
+}
+
+/// Serialize a signature array in canonical transport form.
+///
+/// ```text
+/// len(signatures)_be4 || sig_0 (96 bytes) || sig_1 || ...
+/// ```
+///
+/// Order is preserved exactly. The count prefix matches the `_be4` convention
+/// of `compute_bundle_hash`.
+pub fn serialize_signatures(signatures: &[ValidatorSignature]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(4 + signatures.len() * VALIDATOR_SIGNATURE_BYTES);
+    buf.extend_from_slice(&(signatures.len() as u32).to_be_bytes());
+    for sig in signatures {
+        buf.extend_from_slice(&sig.to_bytes());
+    }
+    buf
+}
+
+/// Decode the output of `serialize_signatures`.
+///
+/// Rejects with `InvalidSerialization` if the buffer is shorter than the count
+/// prefix, or its length is not exactly `4 + count × 96` (no trailing bytes).
+/// Rejects with `PayloadLimitExceeded` if count > `MAX_VALIDATOR_SIGNATURES`.
+/// Ordering is NOT checked here — `verify_quorum` enforces it.
+pub fn deserialize_signatures(bytes: &[u8]) -> Result<Vec<ValidatorSignature>, TransitionError> {
+    if bytes.len() < 4 {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    let count = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+    if count > MAX_VALIDATOR_SIGNATURES {
+        return Err(TransitionError::PayloadLimitExceeded);
+    }
+    let body = &bytes[4..];
+    if body.len() != count * VALIDATOR_SIGNATURE_BYTES {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    let mut out = Vec::with_capacity(count);
+    for chunk in body.chunks_exact(VALIDATOR_SIGNATURE_BYTES) {
+        let mut raw = [0u8; VALIDATOR_SIGNATURE_BYTES];
+        raw.copy_from_slice(chunk);
+        out.push(ValidatorSignature::from_bytes(&raw));
+    }
+    Ok(out)
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // StateWitnessBundle
 // ──────────────────────────────────────────────────────────────────────────────
@@ -255,6 +520,7 @@ pub struct ValidatorSignature {
 /// lexicographic order of `key`. The kernel rejects out-of-order witnesses.
 /// No key may appear in more than one pool's array.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateWitnessBundle {
     /// Witness mutations for the bond pool tree (`EpochState.bond_pool_root`).
     pub bond_witnesses: Vec<LeafMutation>,
@@ -262,6 +528,12 @@ pub struct StateWitnessBundle {
     pub entropy_stats: EntropyStats,
     /// Witness mutations for the impact pool tree (`EpochState.impact_pool_root`).
     pub impact_witnesses: Vec<LeafMutation>,
+    /// Optional per-mutation attribution: `(index, signer pubkey)`, strictly
+    /// ascending by index. `index` counts mutations in `compute_bundle_hash`
+    /// order (bond, impact, validator). Every author must be a quorum signer.
+    /// Empty means collective authorization only (the v0.0.2 behaviour).
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_tooling::mutation_authors"))]
+    pub mutation_authors: Vec<(usize, [u8; 32])>,
     /// Ed25519 signatures authorizing this epoch transition.
     /// Strictly ascending pubkey order, no duplicates.
     /// HOST-TRUSTED (v0.0.2): Pubkeys are NOT verified against validator_set_root.
@@ -270,11 +542,23 @@ pub struct StateWitnessBundle {
     /// Witness mutations for the validator set tree (`EpochState.validator_set_root`).
     /// Processed in two passes: registration first, then decay.
     pub validator_witnesses: Vec<LeafMutation>,
+    /// VDF proof for this epoch, if any. The new `vdf_challenge_seed` is the
+    /// verifier's output over this proof (see `physics::vdf`); the bundle has no
+    /// field through which a seed itself could be supplied.
+    /// Covered by `compute_bundle_hash`, so a relayer cannot swap the proof
+    /// (and with it the seed) under the validators' signatures.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_tooling::hex_opt_bytes"))]
+    pub vdf_proof: Option<Vec<u8>>,
 }
 
 impl StateWitnessBundle {
-    /// Validate the combined payload count against `MAX_PAYLOADS_PER_EPOCH`.
+    /// Validate the combined payload count against `MAX_PAYLOADS_PER_EPOCH`
+    /// and every mutation against `LeafMutation::validate_sizes`.
     /// Called before any Merkle verification — reject oversized bundles immediately.
+    ///
+    /// These bounds are what make the fixed-width length prefixes in
+    /// `compute_bundle_hash` lossless (counts ≤ 10,000 < 2^32, keys and values
+    /// ≤ 4096 < 2^16) on every target pointer width.
     pub fn validate_limits(&self) -> Result<(), TransitionError> {
         let total = self.bond_witnesses.len()
             .saturating_add(self.impact_witnesses.len())
@@ -285,8 +569,154 @@ impl StateWitnessBundle {
         if self.validator_signatures.len() > MAX_VALIDATOR_SIGNATURES {
             return Err(TransitionError::PayloadLimitExceeded);
         }
+        if self.mutation_authors.len() > total {
+            return Err(TransitionError::PayloadLimitExceeded);
+        }
+        // Author indices: strictly ascending (one author per mutation) and in range.
+        for (i, (index, _)) in self.mutation_authors.iter().enumerate() {
+            if *index >= total || (i > 0 && self.mutation_authors[i - 1].0 >= *index) {
+                return Err(TransitionError::InvalidSerialization);
+            }
+        }
+        for m in self.bond_witnesses.iter()
+            .chain(&self.impact_witnesses)
+            .chain(&self.validator_witnesses)
+        {
+            m.validate_sizes()?;
+        }
         Ok(())
     }
+
+    /// Human-inspectable canonical JSON of the whole bundle, for audit logs.
+    ///
+    /// REPORTING FORMAT ONLY — the consensus commitment is `compute_bundle_hash`.
+    /// Field names (frozen): the struct's own field names; mutations as
+    /// `{append_proof, key, new_value, old_value, path: [{position, sibling}]}`
+    /// with hex byte strings, `"left"`/`"right"` positions, and `append_proof`
+    /// as `{leaf_hash, path}` or `null`; entropy counters as numeric
+    /// strings; signatures as `{signature, validator_pubkey}` hex; authors as
+    /// `{index, validator_pubkey}` with a numeric-string index; `vdf_proof`
+    /// as hex or `null`.
+    ///
+    /// Subject to the canonical JSON limits (`MAX_ARRAY_ITEMS`, `MAX_INPUT_BYTES`):
+    /// bundles too large to audit this way return `InvalidSerialization`.
+    pub fn to_canonical_json(&self) -> Result<Vec<u8>, TransitionError> {
+        use crate::physics::canonical_json::{CanonicalObject, Value};
+
+        fn path_nodes(path: &MerklePath) -> Value {
+            Value::Array(path.nodes.iter().map(|n| {
+                let position: &[u8] = match n.position {
+                    NodePosition::Left  => b"left",
+                    NodePosition::Right => b"right",
+                };
+                CanonicalObject::new()
+                    .string("position", position)
+                    .string("sibling", &hex(&n.sibling))
+                    .into_value()
+            }).collect())
+        }
+
+        fn mutations(ms: &[LeafMutation]) -> Value {
+            Value::Array(ms.iter().map(|m| {
+                let append_proof = match &m.append_proof {
+                    Some(proof) => CanonicalObject::new()
+                        .string("leaf_hash", &hex(&proof.leaf_hash))
+                        .value("path", path_nodes(&proof.path))
+                        .into_value(),
+                    None => Value::Null,
+                };
+                CanonicalObject::new()
+                    .value("append_proof", append_proof)
+                    .string("key", &hex(&m.key))
+                    .string("new_value", &hex(&m.new_value))
+                    .string("old_value", &hex(&m.old_value))
+                    .value("path", path_nodes(&m.path))
+                    .into_value()
+            }).collect())
+        }
+
+        let stats = &self.entropy_stats;
+        let entropy_stats = CanonicalObject::new()
+            .string("active_bonded_magnitude_raw", stats.active_bonded_magnitude_raw.to_string().as_bytes())
+            .string("optimal_validator_count", stats.optimal_validator_count.to_string().as_bytes())
+            .string("total_supply_raw", stats.total_supply_raw.to_string().as_bytes())
+            .string("unique_active_validators", stats.unique_active_validators.to_string().as_bytes())
+            .into_value();
+
+        let signatures = Value::Array(self.validator_signatures.iter().map(|sig| {
+            CanonicalObject::new()
+                .string("signature", &hex(&sig.signature))
+                .string("validator_pubkey", &hex(&sig.validator_pubkey))
+                .into_value()
+        }).collect());
+
+        let authors = Value::Array(self.mutation_authors.iter().map(|(index, pubkey)| {
+            CanonicalObject::new()
+                .string("index", index.to_string().as_bytes())
+                .string("validator_pubkey", &hex(pubkey))
+                .into_value()
+        }).collect());
+
+        let vdf_proof = match &self.vdf_proof {
+            Some(proof) => Value::Str(hex(proof)),
+            None        => Value::Null,
+        };
+
+        CanonicalObject::new()
+            .value("bond_witnesses", mutations(&self.bond_witnesses))
+            .value("entropy_stats", entropy_stats)
+            .value("impact_witnesses", mutations(&self.impact_witnesses))
+            .value("mutation_authors", authors)
+            .value("validator_signatures", signatures)
+            .value("validator_witnesses", mutations(&self.validator_witnesses))
+            .value("vdf_proof", vdf_proof)
+            .finish()
+    }
+}
+
+/// `to_hex` as bytes, for the audit JSON form.
+pub(crate) fn hex(bytes: &[u8]) -> Vec<u8> {
+    to_hex(bytes).into_bytes()
+}
+
+/// Reject any DELETE (`new_value == []`) in `mutations` with `InvalidSerialization`.
+///
+/// Applied to pools whose leaves are immutable records (impact, bond), where a
+/// tombstone would corrupt the record set. See module doc §DELETE Semantics.
+pub fn reject_deletions(mutations: &[LeafMutation]) -> Result<(), TransitionError> {
+    if mutations.iter().any(|m| m.new_value.is_empty()) {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    Ok(())
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// WorkMeter
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Computational work performed by a transition, for host billing and rate limits.
+///
+/// Purely observational: the meter is written by the kernel but never read by
+/// it, so it cannot influence any root, error, or control flow.
+///
+/// - `sha256_invocations`: calls to `sha256` (including `hash_leaf`/`hash_node`).
+///   SHA-512 inside Ed25519 verification is NOT counted here.
+/// - `signature_verifications`: Ed25519 `verify_strict` calls.
+/// - `merkle_steps`: path levels walked (one per sibling combined).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WorkMeter {
+    pub sha256_invocations:      u64,
+    pub signature_verifications: u64,
+    pub merkle_steps:            u64,
+}
+
+impl WorkMeter {
+    /// Record one full walk of `path` (one hash per level).
+    fn record_walk(&mut self, path: &MerklePath) {
+        let depth = path.nodes.len() as u64;
+        self.sha256_invocations += depth;
+        self.merkle_steps += depth;
+    }
 }
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -296,13 +726,15 @@ impl StateWitnessBundle {
 /// Domain separation prefix for epoch signing root (distinct from leaf=0x00, node=0x01).
 const SIGNING_DOMAIN_PREFIX: u8 = 0x02;
 
-/// Compute the canonical hash of all three mutation vectors.
+/// Compute the canonical hash of all three mutation vectors and the VDF proof.
 ///
 /// Format (frozen — any change forks the protocol):
 /// ```text
 /// len(bond_witnesses)_be4 || bond_bytes ||
 /// len(impact_witnesses)_be4 || impact_bytes ||
-/// len(validator_witnesses)_be4 || validator_bytes
+/// len(validator_witnesses)_be4 || validator_bytes ||
+/// 0x00 | 0x01 || len(vdf_proof)_be8 || vdf_proof                — absent | present
+/// [ || len(mutation_authors)_be4 || (index_be4 || pubkey)* ]   — only if non-empty
 /// ```
 ///
 /// Where each mutation is serialized as:
@@ -311,35 +743,56 @@ const SIGNING_DOMAIN_PREFIX: u8 = 0x02;
 /// ```
 ///
 /// Path data is NOT included — paths are structural, not content.
+///
+/// Streamed through `Sha256`: the serialized bundle is never materialized.
 pub fn compute_bundle_hash(witness: &StateWitnessBundle) -> Digest {
-    let mut buf = Vec::new();
-    serialize_mutations(&mut buf, &witness.bond_witnesses);
-    serialize_mutations(&mut buf, &witness.impact_witnesses);
-    serialize_mutations(&mut buf, &witness.validator_witnesses);
-    sha256(&buf)
+    let mut hasher = Sha256::new();
+    serialize_mutations(&mut hasher, &witness.bond_witnesses);
+    serialize_mutations(&mut hasher, &witness.impact_witnesses);
+    serialize_mutations(&mut hasher, &witness.validator_witnesses);
+    // The proof determines the next VDF seed, so signers must commit to it.
+    // be8 is lossless for any proof length on every target.
+    match &witness.vdf_proof {
+        Some(proof) => {
+            hasher.update(&[0x01]).update(&(proof.len() as u64).to_be_bytes()).update(proof);
+        }
+        None => {
+            hasher.update(&[0x00]);
+        }
+    }
+    // Attribution is appended only when present, so unattributed bundles keep
+    // their original hash. be4 is lossless: `validate_limits` caps the count.
+    if !witness.mutation_authors.is_empty() {
+        hasher.update(&(witness.mutation_authors.len() as u32).to_be_bytes());
+        for (index, pubkey) in &witness.mutation_authors {
+            hasher.update(&(*index as u32).to_be_bytes()).update_digest(pubkey);
+        }
+    }
+    hasher.finalize()
 }
 
-/// Serialize a mutation vector in canonical format.
-fn serialize_mutations(buf: &mut Vec<u8>, mutations: &[LeafMutation]) {
+/// Feed a mutation vector, in canonical format, into `hasher`.
+///
+/// The `as u32` / `as u16` casts are lossless only for bundles that passed
+/// `StateWitnessBundle::validate_limits`, which `apply_epoch` runs first.
+fn serialize_mutations(hasher: &mut Sha256, mutations: &[LeafMutation]) {
     // 4-byte big-endian count (max 10,000 fits in u32).
-    buf.extend_from_slice(&(mutations.len() as u32).to_be_bytes());
+    hasher.update(&(mutations.len() as u32).to_be_bytes());
     for m in mutations {
         // key: 2-byte len + bytes
-        buf.extend_from_slice(&(m.key.len() as u16).to_be_bytes());
-        buf.extend_from_slice(&m.key);
+        hasher.update(&(m.key.len() as u16).to_be_bytes()).update(&m.key);
         // old_value: 2-byte len + bytes
-        buf.extend_from_slice(&(m.old_value.len() as u16).to_be_bytes());
-        buf.extend_from_slice(&m.old_value);
+        hasher.update(&(m.old_value.len() as u16).to_be_bytes()).update(&m.old_value);
         // new_value: 2-byte len + bytes
-        buf.extend_from_slice(&(m.new_value.len() as u16).to_be_bytes());
-        buf.extend_from_slice(&m.new_value);
+        hasher.update(&(m.new_value.len() as u16).to_be_bytes()).update(&m.new_value);
     }
 }
 
 /// Compute the epoch signing root — the digest that validators sign.
 ///
 /// ```text
-/// SHA256(0x02 || prev_state_root || bundle_hash || epoch_number_be8 || kernel_hash)
+/// SHA256(0x02 || prev_state_root || bundle_hash || epoch_number_be8 || kernel_hash
+///        || epoch_duration_secs_be8)
 /// ```
 ///
 /// - `0x02`: domain separation (leaf=0x00, node=0x01, signing=0x02)
@@ -347,23 +800,80 @@ fn serialize_mutations(buf: &mut Vec<u8>, mutations: &[LeafMutation]) {
 /// - `bundle_hash`: binds all witness content
 /// - `epoch_number_be8`: prevents replay
 /// - `kernel_hash`: binds protocol version
+/// - `epoch_duration_secs_be8`: binds the declared epoch cadence
 ///
-/// Total input: 1 + 32 + 32 + 8 + 32 = 105 bytes.
+/// Total input: 1 + 32 + 32 + 8 + 32 + 8 = 113 bytes.
 pub fn compute_epoch_signing_root(
     prev_state_root: &Digest,
     bundle_hash: &Digest,
     epoch_number: u64,
     kernel_hash: &Digest,
+    epoch_duration_secs: u64,
 ) -> Digest {
-    let mut buf = [0u8; 105];
+    let mut buf = [0u8; 113];
     buf[0] = SIGNING_DOMAIN_PREFIX;
     buf[1..33].copy_from_slice(prev_state_root);
     buf[33..65].copy_from_slice(bundle_hash);
     buf[65..73].copy_from_slice(&epoch_number.to_be_bytes());
     buf[73..105].copy_from_slice(kernel_hash);
+    buf[105..113].copy_from_slice(&epoch_duration_secs.to_be_bytes());
     sha256(&buf)
 }
 
+/// Check that every `mutation_authors` entry names a quorum signer.
+///
+/// Call only after `verify_quorum` has accepted `witness.validator_signatures`
+/// (which guarantees ascending pubkey order); index ranges are checked by
+/// `validate_limits`. An empty author list passes: collective authorization.
+/// Returns `InvalidSignature` for an author who did not sign.
+pub fn verify_mutation_authors(witness: &StateWitnessBundle) -> Result<(), TransitionError> {
+    for (_, author) in &witness.mutation_authors {
+        witness
+            .validator_signatures
+            .binary_search_by(|sig| sig.validator_pubkey.cmp(author))
+            .map_err(|_| TransitionError::InvalidSignature)?;
+    }
+    Ok(())
+}
+
+/// Decode a validator-pool key: exactly 64 lowercase hex characters, the
+/// 32-byte Ed25519 public key of the validator the leaf belongs to.
+/// Returns `InvalidSerialization` for any other shape.
+pub fn validator_key_pubkey(key: &[u8]) -> Result<[u8; 32], TransitionError> {
+    if key.len() != 64 {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    from_hex(key)?.try_into().map_err(|_| TransitionError::InvalidSerialization)
+}
+
+/// Bind validator-pool keys to the signer set.
+///
+/// - Every `validator_witnesses` key must be a pubkey in `validator_key_pubkey`
+///   form (`InvalidSerialization` otherwise), so a signer and its set entry
+///   are always the same 32 bytes, never two encodings of one validator.
+/// - A registration (INSERT) that carries a `mutation_authors` entry must be
+///   authored by the key it registers (`InvalidSignature` otherwise): a
+///   validator enters the set under its own signature, not someone else's.
+///   Decay updates and withdrawals may be authored by any signer.
+///
+/// Call after `verify_mutation_authors`, which checks authors are signers.
+pub fn verify_validator_keys(witness: &StateWitnessBundle) -> Result<(), TransitionError> {
+    for m in &witness.validator_witnesses {
+        validator_key_pubkey(&m.key)?;
+    }
+    // Author indices count bond, then impact, then validator mutations.
+    let offset = witness.bond_witnesses.len() + witness.impact_witnesses.len();
+    for (index, author) in &witness.mutation_authors {
+        let Some(m) = index.checked_sub(offset).and_then(|i| witness.validator_witnesses.get(i)) else {
+            continue;
+        };
+        if m.old_value.is_empty() && !m.new_value.is_empty() && validator_key_pubkey(&m.key)? != *author {
+            return Err(TransitionError::InvalidSignature);
+        }
+    }
+    Ok(())
+}
+
 /// Verify quorum: structural checks + cryptographic verification.
 ///
 /// Enforces:
@@ -381,34 +891,108 @@ pub fn verify_quorum(
     signing_root: &Digest,
     optimal_validator_count: u64,
 ) -> Result<(), TransitionError> {
-    use crate::physics::ed25519;
+    verify_quorum_metered(signatures, signing_root, optimal_validator_count, &mut WorkMeter::default())
+}
 
-    // ── Step 1: Structural checks ──────────────────────────────────────────
-    // Strict ascending pubkey order, no duplicates.
-    for i in 1..signatures.len() {
-        if signatures[i].validator_pubkey <= signatures[i - 1].validator_pubkey {
-            // Duplicate or reversed order.
-            return Err(TransitionError::InvalidSerialization);
+/// `verify_quorum`, additionally recording signature verifications in `meter`.
+pub fn verify_quorum_metered(
+    signatures: &[ValidatorSignature],
+    signing_root: &Digest,
+    optimal_validator_count: u64,
+    meter: &mut WorkMeter,
+) -> Result<(), TransitionError> {
+    Ed25519Quorum.verify(signatures, signing_root, quorum_threshold(optimal_validator_count), meter)
+}
+
+/// Minimum signer count for a quorum: ⌈2/3 × optimal_validator_count⌉.
+///
+/// `(2*n + 2) / 3` in integer math. If `optimal_validator_count == 0` the
+/// threshold is 0 and empty signatures are valid (genesis or no-validator epoch).
+pub fn quorum_threshold(optimal_validator_count: u64) -> u64 {
+    (2 * optimal_validator_count).div_ceil(3)
+}
+
+/// A signature scheme for the epoch signature gate.
+///
+/// `apply_epoch` uses `Ed25519Quorum`; `apply_epoch_with_quorum` accepts any
+/// implementation, so an aggregate scheme can replace per-signature checks
+/// without touching the transition. An implementation must reject signatures
+/// that are not in strict ascending pubkey order (`verify_mutation_authors`
+/// relies on it), must not exit early on a bad signature, and must be
+/// deterministic.
+pub trait QuorumVerifier {
+    /// Accept iff at least `threshold` distinct validators signed `signing_root`.
+    fn verify(
+        &self,
+        signatures:   &[ValidatorSignature],
+        signing_root: &Digest,
+        threshold:    u64,
+        meter:        &mut WorkMeter,
+    ) -> Result<(), TransitionError>;
+}
+
+/// The v0.0.2 quorum: one Ed25519 `verify_strict` per signature.
+pub struct Ed25519Quorum;
+
+impl QuorumVerifier for Ed25519Quorum {
+    fn verify(
+        &self,
+        signatures:   &[ValidatorSignature],
+        signing_root: &Digest,
+        threshold:    u64,
+        meter:        &mut WorkMeter,
+    ) -> Result<(), TransitionError> {
+        use crate::physics::ed25519;
+
+        // ── Step 1: Structural checks ──────────────────────────────────────────
+        // Strict ascending pubkey order, no duplicates.
+        for i in 1..signatures.len() {
+            if signatures[i].validator_pubkey <= signatures[i - 1].validator_pubkey {
+                // Duplicate or reversed order.
+                return Err(TransitionError::InvalidSerialization);
+            }
         }
-    }
 
-    // ── Step 2: Cryptographic verification ──────────────────────────────────
-    // Verify ALL signatures before checking threshold.
-    // No early exit — constant-time traversal prevents timing attacks.
-    for sig in signatures {
-        ed25519::verify(&sig.validator_pubkey, signing_root, &sig.signature)?;
-    }
+        // ── Step 2: Cryptographic verification ──────────────────────────────────
+        // Verify ALL signatures before checking threshold.
+        // No early exit — constant-time traversal prevents timing attacks.
+        let mut all_valid = true;
+        for sig in signatures {
+            meter.signature_verifications += 1;
+            all_valid &= ed25519::verify(&sig.validator_pubkey, signing_root, &sig.signature).is_ok();
+        }
+        if !all_valid {
+            return Err(TransitionError::InvalidSignature);
+        }
 
-    // ── Step 3: Threshold check ────────────────────────────────────────────
-    // threshold = ceil(2/3 * n) = (2*n + 2) / 3  (integer math)
-    // Special case: if optimal_validator_count == 0, threshold == 0,
-    // and empty signatures is valid (genesis or no-validator epoch).
-    let threshold = (2 * optimal_validator_count + 2) / 3;
-    if (signatures.len() as u64) < threshold {
-        return Err(TransitionError::InvalidSignature);
+        // ── Step 3: Threshold check ────────────────────────────────────────────
+        if (signatures.len() as u64) < threshold {
+            return Err(TransitionError::InvalidSignature);
+        }
+
+        Ok(())
     }
+}
 
-    Ok(())
+/// Expected validators that did NOT sign: `expected` minus the pubkeys in
+/// `signatures`, in strict ascending order with duplicates removed.
+///
+/// OBSERVABILITY ONLY. This is a liveness diagnostic for operators and plays
+/// no part in quorum verification; it checks neither signatures nor ordering.
+pub fn compute_abstentions(
+    expected: &[[u8; 32]],
+    signatures: &[ValidatorSignature],
+) -> Vec<[u8; 32]> {
+    let mut signed: Vec<[u8; 32]> = signatures.iter().map(|s| s.validator_pubkey).collect();
+    signed.sort_unstable();
+    let mut absent: Vec<[u8; 32]> = expected
+        .iter()
+        .filter(|pk| signed.binary_search(pk).is_err())
+        .copied()
+        .collect();
+    absent.sort_unstable();
+    absent.dedup();
+    absent
 }
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -432,16 +1016,181 @@ pub fn verify_quorum(
 ///    The caller writes this into the new `EpochState`.
 /// 4. An empty mutation list is valid: returns `current_root` unchanged.
 ///    This is the empty-epoch passthrough for pools with no activity.
+/// 5. An INSERT verifies in slot form or frontier form (see module doc), so a
+///    pool can be grown from empty by a sequence of INSERTs whose roots match
+///    `compute_merkle_root` over the inserted-so-far leaves.
+/// 6. The mutation count may not exceed `2^d`, where `d` is the deepest path in
+///    the batch: more distinct keys than the tree has leaves is impossible.
+/// 7. A DELETE must name the committed leaf it removes: `old_value` non-empty,
+///    verified in slot form (see module doc §DELETE Semantics).
+/// 8. Fail-fast ordering: sizes, key order, DELETE shape and the mutation count
+///    are checked over the whole batch before any hashing, so a structurally
+///    invalid batch is rejected at zero SHA-256 cost wherever the fault sits.
 ///
 /// # Errors
 ///
 /// - `InvalidSerialization` — mutations are out of lexicographic key order,
-///   or contain duplicate keys.
+///   contain duplicate keys, or fail `LeafMutation::validate_sizes`.
 /// - `InvalidMerkleWitness` — any mutation's path does not verify against
-///   the current intermediate root.
+///   the current intermediate root, there are more mutations than leaves,
+///   or a DELETE names no committed value.
 pub fn apply_pool_mutations(
     current_root: Digest,
     mutations: &[LeafMutation],
+) -> Result<Digest, TransitionError> {
+    apply_pool_mutations_metered(current_root, mutations, &mut WorkMeter::default())
+}
+
+/// Mempool admission check for one pending mutation against `pool_root`.
+///
+/// Runs exactly the per-mutation checks `apply_epoch` runs: `apply_pool_mutations`
+/// on a one-element batch (`validate_sizes`, path verification in slot or
+/// frontier form, DELETE rules). Returns the root after the mutation.
+///
+/// The key-to-value binding described on `LeafMutation` is not parsed from
+/// leaf values by any kernel path in v0.0.2; a passing mutation is bound to
+/// its key only through the signed bundle.
+pub fn verify_single_mutation(pool_root: Digest, mutation: &LeafMutation) -> Result<Digest, TransitionError> {
+    apply_pool_mutations(pool_root, core::slice::from_ref(mutation))
+}
+
+/// `apply_pool_mutations`, additionally recording hashing and path work in `meter`.
+pub fn apply_pool_mutations_metered(
+    current_root: Digest,
+    mutations: &[LeafMutation],
+    meter: &mut WorkMeter,
+) -> Result<Digest, TransitionError> {
+    apply_mutations(current_root, mutations, None, meter)
+}
+
+/// Authenticated copy of a leaf next to a sorted INSERT, supplied by the host
+/// so the kernel can see the content the INSERT must sort between.
+#[derive(Clone, Debug)]
+pub struct NeighbourLeaf {
+    /// Canonical bytes of the leaf. Must be non-empty.
+    pub value: Vec<u8>,
+    /// Authentication path of that leaf against the pre-insert root.
+    pub path: MerklePath,
+}
+
+/// Neighbour context for one mutation of `apply_pool_mutations_sorted`.
+#[derive(Clone, Debug, Default)]
+pub struct InsertNeighbours {
+    /// The leaf immediately before the INSERT: the pool's current last leaf
+    /// for an append, leaf `i − 1` for a tombstone refill at index `i > 0`.
+    pub predecessor: Option<NeighbourLeaf>,
+    /// The leaf immediately after a tombstone refill, at index `i + 1`.
+    /// Ignored for appends and when `i` is the last slot of the tree.
+    pub successor: Option<NeighbourLeaf>,
+}
+
+/// `apply_pool_mutations`, additionally enforcing that every INSERT keeps the
+/// pool's live (non-tombstone) leaves in strictly ascending content order.
+///
+/// `neighbours[i]` is the context for `mutations[i]` (ignored unless it is an
+/// INSERT). The two slices must have equal length.
+///
+/// # Sorted INSERT Rules
+///
+/// - Into an empty pool (root `hash_leaf([])`): no neighbours are needed.
+/// - A frontier append: the predecessor must authenticate against the current
+///   intermediate root at exactly the previous leaf index, with
+///   `predecessor.value < new_value`. The append proof pins the real leaf
+///   count at the append index, so a predecessor at a padding index (e.g.
+///   index 3 of a 3-leaf tree) is rejected with it.
+/// - A slot-form INSERT into a non-empty pool refills a tombstone at index `i`:
+///   it must sort strictly between the live leaves at `i − 1` (unless `i` is
+///   0) and `i + 1` (unless `i` is the tree's last slot). A tombstone next to
+///   another tombstone cannot be refilled, since neither neighbour shows where
+///   the live leaves around it sort. Neither can a tombstoned last leaf with
+///   padding after it: the padding copies would keep the old empty value.
+///
+/// Strict ordering also makes pool values unique, which removes the
+/// duplicate-padding ambiguity described in the module doc. UPDATEs are not
+/// re-checked here; keeping an updated value in place is the host's concern.
+///
+/// # Errors
+///
+/// As `apply_pool_mutations`, plus:
+/// - `InvalidSerialization` — slice lengths differ, a required neighbour is
+///   missing or empty, or the new value is out of order.
+/// - `InvalidMerkleWitness` — a neighbour does not authenticate at the index
+///   next to the INSERT.
+pub fn apply_pool_mutations_sorted(
+    current_root: Digest,
+    mutations:    &[LeafMutation],
+    neighbours:   &[InsertNeighbours],
+) -> Result<Digest, TransitionError> {
+    if neighbours.len() != mutations.len() {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    apply_mutations(current_root, mutations, Some(neighbours), &mut WorkMeter::default())
+}
+
+/// Check that the INSERT `mutation` sorts between its live neighbours.
+fn check_sorted_insert(
+    mutation:     &LeafMutation,
+    neighbours:   &InsertNeighbours,
+    current_root: Digest,
+    slot_form:    bool,
+    meter:        &mut WorkMeter,
+) -> Result<(), TransitionError> {
+    if current_root == hash_leaf(&[]) && slot_form {
+        // First leaf of an empty pool: nothing to sort against.
+        return Ok(());
+    }
+    let index = mutation.path.leaf_index();
+    let has_predecessor = !slot_form || index > 0;
+    let has_successor = slot_form && !mutation.path.nodes.iter().all(|n| n.position == NodePosition::Right);
+
+    if has_predecessor {
+        let predecessor = neighbours.predecessor.as_ref().ok_or(TransitionError::InvalidSerialization)?;
+        let at = index.checked_sub(1).ok_or(TransitionError::InvalidMerkleWitness)?;
+        check_neighbour(predecessor, at, current_root, meter)?;
+        if predecessor.value >= mutation.new_value {
+            return Err(TransitionError::InvalidSerialization);
+        }
+    }
+    if has_successor {
+        let successor = neighbours.successor.as_ref().ok_or(TransitionError::InvalidSerialization)?;
+        let at = index.checked_add(1).ok_or(TransitionError::InvalidMerkleWitness)?;
+        check_neighbour(successor, at, current_root, meter)?;
+        if successor.value <= mutation.new_value {
+            return Err(TransitionError::InvalidSerialization);
+        }
+    }
+    Ok(())
+}
+
+/// Check that `neighbour` is a live leaf at `index` under `current_root`.
+fn check_neighbour(
+    neighbour:    &NeighbourLeaf,
+    index:        u64,
+    current_root: Digest,
+    meter:        &mut WorkMeter,
+) -> Result<(), TransitionError> {
+    if neighbour.value.is_empty() {
+        return Err(TransitionError::InvalidSerialization);
+    }
+    if neighbour.path.nodes.len() > MAX_MERKLE_DEPTH {
+        return Err(TransitionError::InvalidMerkleWitness);
+    }
+    meter.sha256_invocations += 1;
+    meter.record_walk(&neighbour.path);
+    neighbour.path.verify(hash_leaf(&neighbour.value), current_root)?;
+    if neighbour.path.leaf_index() != index {
+        return Err(TransitionError::InvalidMerkleWitness);
+    }
+    Ok(())
+}
+
+/// The single mutation loop behind `apply_pool_mutations*`. With
+/// `neighbours`, INSERTs are additionally checked by `check_sorted_insert`.
+fn apply_mutations(
+    current_root: Digest,
+    mutations:    &[LeafMutation],
+    neighbours:   Option<&[InsertNeighbours]>,
+    meter:        &mut WorkMeter,
 ) -> Result<Digest, TransitionError> {
     // ── Step 1: Empty fast path ───────────────────────────────────────────────
     // No mutations → root is unchanged. Valid for pools with no epoch activity.
@@ -449,31 +1198,77 @@ pub fn apply_pool_mutations(
         return Ok(current_root);
     }
 
-    // ── Step 2: Enforce strictly ascending key ordering ───────────────────────
+    // ── Step 2: Structural pre-pass (no hashing) ──────────────────────────────
+    // Every check that needs no SHA-256 runs over the whole batch before the
+    // first path is walked, so a batch that is malformed anywhere — even at
+    // its last mutation — costs comparisons, not hashing.
+    //
     // Keys must be strictly increasing (no duplicates, no reversal).
     // This rule is from witness_schema.md §Witness Validity Invariants (4).
-    for i in 1..mutations.len() {
-        if mutations[i - 1].key >= mutations[i].key {
+    // A DELETE of an empty slot removes nothing: there is no committed value
+    // to prove, so there is nothing to delete.
+    // `MerklePath.nodes` is public, so a path built without `MerklePath::new`
+    // can exceed MAX_MERKLE_DEPTH: re-check every path depth here.
+    for (i, mutation) in mutations.iter().enumerate() {
+        mutation.validate_sizes()?;
+        let too_deep = |path: &MerklePath| path.nodes.len() > MAX_MERKLE_DEPTH;
+        if too_deep(&mutation.path) || mutation.append_proof.as_ref().is_some_and(|p| too_deep(&p.path)) {
+            return Err(TransitionError::InvalidMerkleWitness);
+        }
+        if i > 0 && mutations[i - 1].key >= mutation.key {
             return Err(TransitionError::InvalidSerialization);
         }
+        if mutation.new_value.is_empty() && mutation.old_value.is_empty() {
+            return Err(TransitionError::InvalidMerkleWitness);
+        }
+    }
+
+    // ── Step 2b: Mutation count must fit the deepest tree the paths describe ──
+    // Distinct keys name distinct leaves, and a tree never gets shallower, so
+    // every mutated leaf lives in a tree of at most 2^max_depth leaves.
+    // Step 2 bounded every depth by MAX_MERKLE_DEPTH (40), so the shift
+    // cannot overflow a u64.
+    let max_depth = mutations.iter().map(|m| m.path.nodes.len()).max().unwrap_or(0);
+    if mutations.len() as u64 > 1u64 << max_depth {
+        return Err(TransitionError::InvalidMerkleWitness);
     }
 
     // ── Step 3: Evolving-root verification loop (Model A) ─────────────────────
     let mut intermediate_root = current_root;
 
-    for mutation in mutations {
-        // 3a. Compute old leaf hash.
+    for (i, mutation) in mutations.iter().enumerate() {
+        // 3a. Compute old and new leaf hashes.
         //     hash_leaf([]) == empty_tree_root() for INSERT case — correct by spec.
         let old_leaf_hash = hash_leaf(&mutation.old_value);
+        let new_leaf_hash = hash_leaf(&mutation.new_value);
+        meter.sha256_invocations += 2;
 
         // 3b. Verify the path against the CURRENT intermediate root, not the
         //     original pool root. This enforces Model A: stale paths from
         //     before a prior mutation fail here.
-        mutation.path.verify(old_leaf_hash, intermediate_root)?;
+        //     An INSERT that does not verify in slot form may still verify in
+        //     frontier form (append as the new last leaf).
+        meter.record_walk(&mutation.path);
+        let slot_form = mutation.path.verify(old_leaf_hash, intermediate_root);
+        if let Err(e) = slot_form.clone() {
+            let is_frontier_append = mutation.old_value.is_empty()
+                && mutation.path.prior_root_before_append(new_leaf_hash, mutation.append_proof.as_ref(), meter)
+                    == Some(intermediate_root);
+            if !is_frontier_append {
+                return Err(e);
+            }
+        }
+
+        // 3b'. Sorted mode: an INSERT must sort between its live neighbours.
+        if let Some(neighbours) = neighbours {
+            if mutation.old_value.is_empty() && !mutation.new_value.is_empty() {
+                check_sorted_insert(mutation, &neighbours[i], intermediate_root, slot_form.is_ok(), meter)?;
+            }
+        }
 
         // 3c. Reconstruct the new intermediate root using the new leaf value.
-        let new_leaf_hash = hash_leaf(&mutation.new_value);
         intermediate_root = mutation.path.reconstruct_root(new_leaf_hash);
+        meter.record_walk(&mutation.path);
     }
 
     // ── Step 4: Return the final root ─────────────────────────────────────────
@@ -620,6 +1415,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hand_built_over_deep_paths_are_rejected_before_hashing() {
+        let deep = |depth: usize| MerklePath {
+            nodes: vec![MerklePathNode { sibling: [0u8; 32], position: NodePosition::Right }; depth],
+        };
+        let insert = |path: MerklePath, append_proof: Option<AppendProof>| LeafMutation {
+            key:          b"k".to_vec(),
+            old_value:    vec![],
+            new_value:    b"v".to_vec(),
+            path,
+            append_proof,
+        };
+        // 64 and more levels would overflow the Step 2b shift; 41 is just past the limit.
+        for depth in [MAX_MERKLE_DEPTH + 1, 64, 65, 200] {
+            let mut meter = WorkMeter::default();
+            assert_eq!(
+                apply_pool_mutations_metered(hash_leaf(&[]), &[insert(deep(depth), None)], &mut meter),
+                Err(TransitionError::InvalidMerkleWitness),
+                "depth {}", depth
+            );
+            assert_eq!(meter.sha256_invocations, 0, "depth {}", depth);
+
+            let proof = AppendProof { leaf_hash: [0u8; 32], path: deep(depth) };
+            assert_eq!(
+                apply_pool_mutations(hash_leaf(&[]), &[insert(deep(1), Some(proof))]),
+                Err(TransitionError::InvalidMerkleWitness),
+                "append proof depth {}", depth
+            );
+        }
+        // leaf_index never panics, even past 64 levels.
+        assert_eq!(deep(64).leaf_index(), u64::MAX);
+        assert_eq!(deep(200).leaf_index(), u64::MAX);
+    }
+
     // ── Empty leaf identity (constitutional) ──────────────────────────────────
 
     #[test]
@@ -641,7 +1470,7 @@ mod tests {
             unique_active_validators: 10,
             optimal_validator_count: 100,
         };
-        assert_eq!(bad.validate(), Err(TransitionError::MathOverflow));
+        assert_eq!(bad.validate(), Err(TransitionError::MathUnderflow));
     }
 
     #[test]
@@ -675,6 +1504,7 @@ mod tests {
             old_value: vec![],
             new_value: b"v".to_vec(),
             path: MerklePath::new(vec![]).unwrap(),
+            append_proof: None,
         };
         // MAX_PAYLOADS_PER_EPOCH + 1 total across all pools.
         let bundle = StateWitnessBundle {
@@ -686,8 +1516,10 @@ mod tests {
                 optimal_validator_count: 1,
             },
             impact_witnesses: vec![dummy_mutation; MAX_PAYLOADS_PER_EPOCH / 2 + 1],
+            mutation_authors: vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof: None,
         };
         assert_eq!(bundle.validate_limits(), Err(TransitionError::PayloadLimitExceeded));
     }
@@ -701,6 +1533,7 @@ mod tests {
             old_value: vec![],
             new_value: vec![],
             path: MerklePath::new(vec![]).unwrap(),
+            append_proof: None,
         };
         assert_eq!(m.validate_sizes(), Err(TransitionError::InvalidSerialization));
     }
@@ -712,6 +1545,7 @@ mod tests {
             old_value: vec![0u8; MAX_VALUE_BYTES + 1],
             new_value: vec![],
             path: MerklePath::new(vec![]).unwrap(),
+            append_proof: None,
         };
         assert_eq!(m.validate_sizes(), Err(TransitionError::InvalidSerialization));
     }
@@ -785,6 +1619,7 @@ mod tests {
             old_value: old_value.to_vec(),
             new_value: new_value.to_vec(),
             path: MerklePath::new(vec![MerklePathNode { sibling, position }]).unwrap(),
+            append_proof: None,
         }
     }
 
@@ -908,4 +1743,809 @@ mod tests {
             "stale path from before a prior mutation must fail (Model A enforced)"
         );
     }
+
+    #[test]
+    fn more_mutations_than_tree_capacity_is_rejected() {
+        // Five distinct keys cycling the two leaves of a depth-1 tree: each step
+        // verifies on its own, but a 2-leaf tree cannot hold 5 distinct keys.
+        let leaf_b = hash_leaf(b"b");
+        let values: [&[u8]; 6] = [b"a", b"a1", b"a2", b"a3", b"a4", b"a5"];
+        let mutations: Vec<LeafMutation> = (0..5)
+            .map(|i| make_mutation(&[b'k', b'0' + i as u8], values[i], values[i + 1], leaf_b, NodePosition::Left))
+            .collect();
+        let root = hash_node(&hash_leaf(b"a"), &leaf_b);
+        assert_eq!(apply_pool_mutations(root, &mutations), Err(TransitionError::InvalidMerkleWitness));
+        // Two of them (the tree's capacity) are still accepted.
+        assert_eq!(
+            apply_pool_mutations(root, &mutations[..2]),
+            Ok(hash_node(&hash_leaf(b"a2"), &leaf_b))
+        );
+    }
+
+    #[test]
+    fn structurally_invalid_batch_is_rejected_before_any_hashing() {
+        // 10 000 updates with depth-14 paths (capacity 16 384); none would verify,
+        // but the pre-pass must reject the batch before the first walk.
+        let path = MerklePath::new(vec![MerklePathNode { sibling: [0u8; 32], position: NodePosition::Left }; 14]).unwrap();
+        let mut mutations: Vec<LeafMutation> = (0u32..10_000)
+            .map(|i| LeafMutation {
+                key:          i.to_be_bytes().to_vec(),
+                old_value:    b"old".to_vec(),
+                new_value:    b"new".to_vec(),
+                path:         path.clone(),
+                append_proof: None,
+            })
+            .collect();
+        let mut meter = WorkMeter::default();
+
+        // Out-of-order key at position 9000.
+        let mut reordered = mutations.clone();
+        reordered[9000].key = vec![0];
+        assert_eq!(
+            apply_pool_mutations_metered([0u8; 32], &reordered, &mut meter),
+            Err(TransitionError::InvalidSerialization)
+        );
+        assert_eq!(meter, WorkMeter::default(), "no path hashed before the ordering fault");
+
+        // A DELETE of nothing at the very end.
+        mutations[9_999].old_value.clear();
+        mutations[9_999].new_value.clear();
+        assert_eq!(
+            apply_pool_mutations_metered([0u8; 32], &mutations, &mut meter),
+            Err(TransitionError::InvalidMerkleWitness)
+        );
+        assert_eq!(meter, WorkMeter::default());
+    }
+
+    #[test]
+    fn single_mutation_admission() {
+        // The validator pool of the multi-pool transition test: [v1, v2].
+        let leaf_v1 = hash_leaf(b"v1");
+        let leaf_v2 = hash_leaf(b"v2");
+        let root = hash_node(&leaf_v1, &leaf_v2);
+
+        let ok = make_mutation(b"v1", b"v1", b"v1_updated", leaf_v2, NodePosition::Left);
+        assert_eq!(verify_single_mutation(root, &ok), Ok(hash_node(&hash_leaf(b"v1_updated"), &leaf_v2)));
+        assert_eq!(verify_single_mutation(root, &ok), apply_pool_mutations(root, std::slice::from_ref(&ok)));
+
+        // Wrong side of the tree.
+        let bad_path = make_mutation(b"v1", b"v1", b"v1_updated", leaf_v2, NodePosition::Right);
+        assert_eq!(verify_single_mutation(root, &bad_path), Err(TransitionError::InvalidMerkleWitness));
+
+        // Oversized key: rejected before any hashing.
+        let mut oversized = ok;
+        oversized.key = vec![b'k'; MAX_KEY_BYTES + 1];
+        assert_eq!(verify_single_mutation(root, &oversized), Err(TransitionError::InvalidSerialization));
+    }
+
+    // ── DELETE ────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn delete_must_prove_the_committed_value() {
+        let leaf_a = hash_leaf(b"a");
+        let leaf_b = hash_leaf(b"b");
+        let root = hash_node(&leaf_a, &leaf_b);
+
+        // Correct old_value: the leaf becomes a tombstone.
+        let ok = make_mutation(b"a", b"a", b"", leaf_b, NodePosition::Left);
+        assert_eq!(apply_pool_mutations(root, &[ok]).unwrap(), hash_node(&hash_leaf(&[]), &leaf_b));
+
+        // Guessed old_value at the right position.
+        let wrong = make_mutation(b"a", b"guess", b"", leaf_b, NodePosition::Left);
+        assert_eq!(apply_pool_mutations(root, &[wrong]), Err(TransitionError::InvalidMerkleWitness));
+    }
+
+    #[test]
+    fn delete_of_absent_leaf_is_rejected() {
+        let leaf_b = hash_leaf(b"b");
+        let empty = hash_leaf(&[]);
+        // Slot 0 is already a tombstone: "deleting" it names no committed value.
+        let root = hash_node(&empty, &leaf_b);
+        let nothing = make_mutation(b"a", b"", b"", leaf_b, NodePosition::Left);
+        assert_eq!(apply_pool_mutations(root, &[nothing]), Err(TransitionError::InvalidMerkleWitness));
+
+        // A key that was never inserted, with an invented value.
+        let invented = make_mutation(b"c", b"c", b"", leaf_b, NodePosition::Left);
+        assert_eq!(apply_pool_mutations(root, &[invented]), Err(TransitionError::InvalidMerkleWitness));
+    }
+
+    // ── Building a pool from empty ────────────────────────────────────────────
+
+    fn insert(key: &[u8], value: &[u8], path: MerklePath) -> LeafMutation {
+        LeafMutation { key: key.to_vec(), old_value: vec![], new_value: value.to_vec(), path, append_proof: None }
+    }
+
+    /// Frontier-form INSERT of the last of `leaves`, with the append proof for
+    /// leaf n − 2 of the prior tree `leaves[..n]`.
+    fn append(key: &[u8], value: &[u8], leaves: &[&[u8]]) -> LeafMutation {
+        let n = leaves.len() - 1;
+        let mut m = insert(key, value, leaf_path(leaves, n));
+        if n >= 2 {
+            m.append_proof = Some(AppendProof {
+                leaf_hash: hash_leaf(leaves[n - 2]),
+                path:      leaf_path(&leaves[..n], n - 2),
+            });
+        }
+        m
+    }
+
+    #[test]
+    fn inserts_grow_pool_from_empty_matching_compute_merkle_root() {
+        use crate::physics::merkle::compute_merkle_root;
+
+        // Past 16 leaves: both index parities and every power-of-two crossing.
+        let owned: Vec<Vec<u8>> = (0..20u8).map(|i| vec![b'v', i]).collect();
+        let values: Vec<&[u8]> = owned.iter().map(|v| v.as_slice()).collect();
+        let mut root = empty_tree_root();
+        for n in 1..=values.len() {
+            let m = append(values[n - 1], values[n - 1], &values[..n]);
+            root = apply_pool_mutations(root, &[m])
+                .unwrap_or_else(|e| panic!("insert of leaf {} failed: {:?}", n, e));
+            assert_eq!(root, compute_merkle_root(&owned[..n]).unwrap(),
+                "root after {} inserts must equal compute_merkle_root", n);
+        }
+    }
+
+    #[test]
+    fn insert_sequence_in_one_call_uses_evolving_root() {
+        use crate::physics::merkle::compute_merkle_root;
+
+        // 0 → 1 → 2 → 3 leaves in a single Model A batch.
+        let mutations = vec![
+            append(b"a", b"a", &[b"a"]),
+            append(b"b", b"b", &[b"a", b"b"]),
+            append(b"c", b"c", &[b"a", b"b", b"c"]),
+        ];
+        let root = apply_pool_mutations(empty_tree_root(), &mutations).unwrap();
+        let expected = compute_merkle_root(&[b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]).unwrap();
+        assert_eq!(root, expected);
+    }
+
+    #[test]
+    fn frontier_insert_with_forged_padding_is_rejected() {
+        // Growing [a, b] to three leaves: the level-0 sibling must be the
+        // duplicated new leaf. Any other sibling would claim a fourth leaf.
+        let root = hash_node(&hash_leaf(b"a"), &hash_leaf(b"b"));
+        let forged = MerklePath::new(vec![
+            MerklePathNode { sibling: hash_leaf(b"x"), position: NodePosition::Left },
+            MerklePathNode { sibling: root, position: NodePosition::Right },
+        ]).unwrap();
+        assert_eq!(
+            apply_pool_mutations(root, &[insert(b"c", b"c", forged)]),
+            Err(TransitionError::InvalidMerkleWitness)
+        );
+    }
+
+    #[test]
+    fn frontier_insert_at_even_index_takes_the_prior_last_leaf_from_the_proof() {
+        // Growing six leaves to seven: the prior padding duplicated leaf "f",
+        // which is hidden inside a sibling hash. The proof for leaf 4 names it.
+        use crate::physics::merkle::compute_merkle_root;
+        let values: [&[u8]; 7] = [b"a", b"b", b"c", b"d", b"e", b"f", b"g"];
+        let six: Vec<Vec<u8>> = values[..6].iter().map(|v| v.to_vec()).collect();
+        let root = compute_merkle_root(&six).unwrap();
+        let genuine = append(b"g", b"g", &values);
+        let seven: Vec<Vec<u8>> = values.iter().map(|v| v.to_vec()).collect();
+        assert_eq!(apply_pool_mutations(root, std::slice::from_ref(&genuine)), compute_merkle_root(&seven));
+
+        // Without the proof there is no prior last leaf to pad with, and a
+        // proof naming any other leaf 5 derives a different prior root.
+        let mut missing = genuine.clone();
+        missing.append_proof = None;
+        assert_eq!(apply_pool_mutations(root, &[missing]), Err(TransitionError::InvalidMerkleWitness));
+        let mut forged = genuine;
+        forged.append_proof.as_mut().unwrap().path.nodes[0].sibling = hash_leaf(b"z");
+        assert_eq!(apply_pool_mutations(root, &[forged]), Err(TransitionError::InvalidMerkleWitness));
+    }
+
+    #[test]
+    fn frontier_append_over_a_padded_prior_tree_is_rejected() {
+        // [a, b, c] pads to [a, b, c, c], whose root is also that of a complete
+        // four-leaf tree. Appending x at index 4 would commit the padding copy
+        // of c as a phantom leaf 3: [a, b, c, c, x].
+        use crate::physics::merkle::compute_merkle_root;
+        let three: Vec<Vec<u8>> = [b"a", b"b", b"c"].iter().map(|v| v.to_vec()).collect();
+        let root = compute_merkle_root(&three).unwrap();
+        let phantom: [&[u8]; 5] = [b"a", b"b", b"c", b"c", b"x"];
+
+        // Without an append proof, and with the only proof the prior tree
+        // admits (leaf 2 is c, and so is the padding at leaf 3).
+        let mut m = append(b"x", b"x", &phantom);
+        assert!(m.append_proof.is_some());
+        assert_eq!(apply_pool_mutations(root, std::slice::from_ref(&m)), Err(TransitionError::InvalidMerkleWitness));
+        m.append_proof = None;
+        assert_eq!(apply_pool_mutations(root, &[m]), Err(TransitionError::InvalidMerkleWitness));
+
+        // The genuine append at index 3 is accepted.
+        assert!(apply_pool_mutations(root, &[append(b"x", b"x", &[b"a", b"b", b"c", b"x"])]).is_ok());
+    }
+
+    #[test]
+    fn frontier_append_proof_must_name_leaf_n_minus_two() {
+        let four: [&[u8]; 5] = [b"a", b"b", b"c", b"d", b"x"];
+        let root = hash_node(
+            &hash_node(&hash_leaf(b"a"), &hash_leaf(b"b")),
+            &hash_node(&hash_leaf(b"c"), &hash_leaf(b"d")),
+        );
+        let genuine = append(b"x", b"x", &four);
+        assert!(apply_pool_mutations(root, std::slice::from_ref(&genuine)).is_ok());
+
+        // A real leaf at the wrong index, and a forged leaf at the right one.
+        let mut wrong_index = genuine.clone();
+        wrong_index.append_proof = Some(AppendProof { leaf_hash: hash_leaf(b"a"), path: leaf_path(&four[..4], 0) });
+        assert_eq!(apply_pool_mutations(root, &[wrong_index]), Err(TransitionError::InvalidMerkleWitness));
+        let mut forged = genuine;
+        forged.append_proof.as_mut().unwrap().leaf_hash = hash_leaf(b"z");
+        assert_eq!(apply_pool_mutations(root, &[forged]), Err(TransitionError::InvalidMerkleWitness));
+    }
+
+    // ── INSERT safety ─────────────────────────────────────────────────────────
+
+    /// Authentication path of slot `index` in the padded tree over `leaves`.
+    fn leaf_path(leaves: &[&[u8]], mut index: usize) -> MerklePath {
+        let mut level: Vec<Digest> = leaves.iter().map(|l| hash_leaf(l)).collect();
+        let padded = level.len().next_power_of_two();
+        while level.len() < padded {
+            level.push(*level.last().unwrap());
+        }
+        let mut nodes = Vec::new();
+        while level.len() > 1 {
+            let (sibling, position) = if index.is_multiple_of(2) {
+                (level[index + 1], NodePosition::Left)
+            } else {
+                (level[index - 1], NodePosition::Right)
+            };
+            nodes.push(MerklePathNode { sibling, position });
+            level = level.chunks_exact(2).map(|p| hash_node(&p[0], &p[1])).collect();
+            index /= 2;
+        }
+        MerklePath::new(nodes).unwrap()
+    }
+
+    /// Root of the padded tree over `leaves`, which may include tombstones.
+    fn leaf_root(leaves: &[&[u8]]) -> Digest {
+        leaf_path(leaves, 0).reconstruct_root(hash_leaf(leaves[0]))
+    }
+
+    const FIVE: [&[u8]; 5] = [b"a", b"b", b"c", b"d", b"e"];
+
+    fn five_leaf_root() -> Digest {
+        use crate::physics::merkle::compute_merkle_root;
+        compute_merkle_root(&FIVE.iter().map(|v| v.to_vec()).collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn insert_cannot_overwrite_any_occupied_or_padding_slot() {
+        // Every slot of the padded 8-slot tree holds a leaf (real or padding).
+        // An INSERT on any of them, with the slot's genuine path, must fail.
+        let root = five_leaf_root();
+        for index in 0..8 {
+            let m = insert(b"x", b"x", leaf_path(&FIVE, index));
+            assert_eq!(
+                apply_pool_mutations(root, &[m]),
+                Err(TransitionError::InvalidMerkleWitness),
+                "INSERT over slot {} must be rejected", index
+            );
+        }
+    }
+
+    #[test]
+    fn insert_with_substituted_siblings_cannot_claim_an_empty_slot() {
+        // Crafting siblings cannot make hash_leaf([]) walk to the real root:
+        // that needs a SHA-256 collision with the genuine subtree hashes.
+        let root = five_leaf_root();
+        let candidates = [hash_leaf(&[]), hash_leaf(b"a"), [0u8; 32], root];
+        for index in 0..FIVE.len() {
+            for level in 0..3 {
+                for sibling in candidates {
+                    let mut path = leaf_path(&FIVE, index);
+                    path.nodes[level].sibling = sibling;
+                    let m = insert(b"x", b"x", path);
+                    assert_eq!(
+                        apply_pool_mutations(root, &[m]),
+                        Err(TransitionError::InvalidMerkleWitness)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn frontier_form_cannot_replace_the_last_real_leaf() {
+        // [a, b, c, d]: claiming to append x at index 3 (where d lives) rebuilds
+        // the prior root as [a, b, c] padded, which is not the current root.
+        use crate::physics::merkle::compute_merkle_root;
+        let four: Vec<Vec<u8>> = [b"a", b"b", b"c", b"d"].iter().map(|v| v.to_vec()).collect();
+        let root = compute_merkle_root(&four).unwrap();
+        let odd = append(b"x", b"x", &[b"a", b"b", b"c", b"x"]);
+        assert_eq!(apply_pool_mutations(root, &[odd]), Err(TransitionError::InvalidMerkleWitness));
+        // Same for the power-of-two form at index 2 (where c lives).
+        let pow2 = append(b"x", b"x", &[b"a", b"b", b"x"]);
+        assert_eq!(apply_pool_mutations(root, &[pow2]), Err(TransitionError::InvalidMerkleWitness));
+        // The genuine append at index 4 is still accepted.
+        let append = append(b"x", b"x", &[b"a", b"b", b"c", b"d", b"x"]);
+        assert!(apply_pool_mutations(root, &[append]).is_ok());
+    }
+
+    #[test]
+    fn frontier_append_cannot_promote_padding_to_real_leaves() {
+        // Over the 3-leaf tree [a, b, c] (padded [a, b, c, c]) an append at
+        // index 4 treats the padding c as a real leaf 3.
+        use crate::physics::merkle::compute_merkle_root;
+        let to_vecs = |vs: &[&[u8]]| vs.iter().map(|v| v.to_vec()).collect::<Vec<_>>();
+        let root = compute_merkle_root(&to_vecs(&[b"a", b"b", b"c"])).unwrap();
+        let m = append(b"x", b"x", &[b"a", b"b", b"c", b"c", b"x"]);
+        assert_eq!(apply_pool_mutations(root, &[m]), Err(TransitionError::InvalidMerkleWitness));
+
+        // The odd-index form of the same attack: over six leaves (padded with
+        // f at index 6 and 7), an append at index 7 treats the padding f at
+        // index 6 as real.
+        let six: [&[u8]; 6] = [b"a", b"b", b"c", b"d", b"e", b"f"];
+        let root = compute_merkle_root(&to_vecs(&six)).unwrap();
+        let m = append(b"x", b"x", &[b"a", b"b", b"c", b"d", b"e", b"f", b"f", b"x"]);
+        assert_eq!(apply_pool_mutations(root, &[m]), Err(TransitionError::InvalidMerkleWitness));
+
+        // The even-index form: over five leaves (padded with e at 5, 6 and 7),
+        // an append at index 6 treats the padding e at index 5 as real.
+        let root = compute_merkle_root(&to_vecs(&six[..5])).unwrap();
+        let m = append(b"x", b"x", &[b"a", b"b", b"c", b"d", b"e", b"e", b"x"]);
+        assert_eq!(apply_pool_mutations(root, &[m]), Err(TransitionError::InvalidMerkleWitness));
+    }
+
+    // ── Sorted INSERTs ────────────────────────────────────────────────────────
+
+    fn neighbour(leaves: &[&[u8]], index: usize) -> Option<NeighbourLeaf> {
+        Some(NeighbourLeaf { value: leaves[index].to_vec(), path: leaf_path(leaves, index) })
+    }
+
+    /// Neighbours of an append after the last of `leaves`.
+    fn after_last(leaves: &[&[u8]]) -> InsertNeighbours {
+        InsertNeighbours { predecessor: neighbour(leaves, leaves.len() - 1), successor: None }
+    }
+
+    #[test]
+    fn leaf_index_reads_positions_as_bits() {
+        for index in 0..FIVE.len() {
+            assert_eq!(leaf_path(&FIVE, index).leaf_index(), index as u64);
+        }
+    }
+
+    #[test]
+    fn in_order_inserts_are_accepted() {
+        use crate::physics::merkle::compute_merkle_root;
+
+        // 0 → 20 leaves in one batch: each append names the prior last leaf.
+        let owned: Vec<Vec<u8>> = (0..20u8).map(|i| vec![b'v', i]).collect();
+        let values: Vec<&[u8]> = owned.iter().map(|v| v.as_slice()).collect();
+        let mutations: Vec<LeafMutation> = (1..=values.len())
+            .map(|n| append(values[n - 1], values[n - 1], &values[..n]))
+            .collect();
+        let neighbours: Vec<InsertNeighbours> = (1..=values.len())
+            .map(|n| if n == 1 { InsertNeighbours::default() } else { after_last(&values[..n - 1]) })
+            .collect();
+        let root = apply_pool_mutations_sorted(empty_tree_root(), &mutations, &neighbours).unwrap();
+        assert_eq!(root, compute_merkle_root(&owned).unwrap());
+    }
+
+    #[test]
+    fn out_of_order_insert_is_rejected() {
+        // Appending "0" after "e" is a valid append but breaks the sort order.
+        let root = five_leaf_root();
+        let grown: [&[u8]; 6] = [b"a", b"b", b"c", b"d", b"e", b"0"];
+        let m = append(b"k", b"0", &grown);
+        assert!(apply_pool_mutations(root, std::slice::from_ref(&m)).is_ok());
+        assert_eq!(
+            apply_pool_mutations_sorted(root, std::slice::from_ref(&m), &[after_last(&FIVE)]),
+            Err(TransitionError::InvalidSerialization)
+        );
+        // An equal value is also out of order (values must be unique).
+        let dup: [&[u8]; 6] = [b"a", b"b", b"c", b"d", b"e", b"e"];
+        let m = append(b"k", b"e", &dup);
+        assert_eq!(
+            apply_pool_mutations_sorted(root, &[m], &[after_last(&FIVE)]),
+            Err(TransitionError::InvalidSerialization)
+        );
+    }
+
+    #[test]
+    fn sorted_insert_requires_the_genuine_predecessor() {
+        let root = five_leaf_root();
+        let grown: [&[u8]; 6] = [b"a", b"b", b"c", b"d", b"e", b"f"];
+        let m = append(b"k", b"f", &grown);
+        let with = |predecessor| [InsertNeighbours { predecessor, successor: None }];
+
+        // Missing context, or the wrong slice length.
+        assert_eq!(apply_pool_mutations_sorted(root, std::slice::from_ref(&m), &with(None)), Err(TransitionError::InvalidSerialization));
+        assert_eq!(apply_pool_mutations_sorted(root, std::slice::from_ref(&m), &[]), Err(TransitionError::InvalidSerialization));
+        // A real leaf that is not the last one ("a" at index 0).
+        let early = neighbour(&FIVE, 0);
+        assert_eq!(apply_pool_mutations_sorted(root, std::slice::from_ref(&m), &with(early)), Err(TransitionError::InvalidMerkleWitness));
+        // A forged value at the right index.
+        let forged = Some(NeighbourLeaf { value: b"0".to_vec(), path: leaf_path(&FIVE, 4) });
+        assert_eq!(apply_pool_mutations_sorted(root, std::slice::from_ref(&m), &with(forged)), Err(TransitionError::InvalidMerkleWitness));
+        // The genuine predecessor is accepted.
+        assert!(apply_pool_mutations_sorted(root, &[m], &[after_last(&FIVE)]).is_ok());
+    }
+
+    #[test]
+    fn sorted_insert_rejects_a_predecessor_in_the_padding() {
+        // [a, b, c] pads to [a, b, c, c]: the copy of c at index 3 authenticates,
+        // but it is not a real leaf, so appending after it must fail.
+        use crate::physics::merkle::compute_merkle_root;
+        let three: [&[u8]; 3] = [b"a", b"b", b"c"];
+        let root = compute_merkle_root(&three.iter().map(|v| v.to_vec()).collect::<Vec<_>>()).unwrap();
+        let padding = NeighbourLeaf { value: b"c".to_vec(), path: leaf_path(&three, 3) };
+        let m = append(b"k", b"d", &[b"a", b"b", b"c", b"c", b"d"]);
+        assert_eq!(
+            apply_pool_mutations_sorted(root, &[m], &[InsertNeighbours { predecessor: Some(padding), successor: None }]),
+            Err(TransitionError::InvalidMerkleWitness)
+        );
+        // The real last leaf at index 2 is the genuine predecessor.
+        let m = append(b"k", b"d", &[b"a", b"b", b"c", b"d"]);
+        assert!(apply_pool_mutations_sorted(root, &[m], &[after_last(&three)]).is_ok());
+    }
+
+    #[test]
+    fn tombstone_refill_must_sort_between_its_live_neighbours() {
+        // [a, b, _, d, e, f, g, h]: "c" was deleted, leaving a tombstone at 2.
+        let live: [&[u8]; 8] = [b"a", b"b", b"", b"d", b"e", b"f", b"g", b"h"];
+        let root = leaf_root(&live);
+        let refill = |value: &[u8], index: usize| insert(b"k", value, leaf_path(&live, index));
+        let around = |index: usize| InsertNeighbours {
+            predecessor: index.checked_sub(1).and_then(|i| neighbour(&live, i)),
+            successor:   neighbour(&live, index + 1),
+        };
+
+        assert!(apply_pool_mutations_sorted(root, &[refill(b"c", 2)], &[around(2)]).is_ok());
+        for out_of_order in [&b"0"[..], b"b", b"d", b"z"] {
+            assert_eq!(
+                apply_pool_mutations_sorted(root, &[refill(out_of_order, 2)], &[around(2)]),
+                Err(TransitionError::InvalidSerialization),
+                "{:?}", out_of_order
+            );
+        }
+        // Each side is required, and must be the leaf right next to the slot.
+        let no_successor = InsertNeighbours { successor: None, ..around(2) };
+        assert_eq!(apply_pool_mutations_sorted(root, &[refill(b"c", 2)], &[no_successor]), Err(TransitionError::InvalidSerialization));
+        let no_predecessor = InsertNeighbours { predecessor: None, ..around(2) };
+        assert_eq!(apply_pool_mutations_sorted(root, &[refill(b"c", 2)], &[no_predecessor]), Err(TransitionError::InvalidSerialization));
+        let far = InsertNeighbours { successor: neighbour(&live, 4), ..around(2) };
+        assert_eq!(apply_pool_mutations_sorted(root, &[refill(b"c", 2)], &[far]), Err(TransitionError::InvalidMerkleWitness));
+
+        // At the edges one side is the edge of the tree.
+        let edges: [&[u8]; 8] = [b"", b"b", b"c", b"d", b"e", b"f", b"g", b""];
+        let root = leaf_root(&edges);
+        let first = insert(b"k", b"a", leaf_path(&edges, 0));
+        let before_b = InsertNeighbours { predecessor: None, successor: neighbour(&edges, 1) };
+        assert!(apply_pool_mutations_sorted(root, &[first], &[before_b]).is_ok());
+        let last = insert(b"k", b"h", leaf_path(&edges, 7));
+        let after_g = InsertNeighbours { predecessor: neighbour(&edges, 6), successor: None };
+        assert!(apply_pool_mutations_sorted(root, &[last], &[after_g]).is_ok());
+    }
+
+    #[test]
+    fn tombstone_next_to_a_tombstone_cannot_be_refilled() {
+        // [a, _, _, d]: neither empty neighbour shows where "b" must sort.
+        let live: [&[u8]; 4] = [b"a", b"", b"", b"d"];
+        let root = leaf_root(&live);
+        let m = insert(b"k", b"b", leaf_path(&live, 1));
+        let tombstone = NeighbourLeaf { value: vec![], path: leaf_path(&live, 2) };
+        let neighbours = InsertNeighbours { predecessor: neighbour(&live, 0), successor: Some(tombstone) };
+        assert!(apply_pool_mutations(root, std::slice::from_ref(&m)).is_ok());
+        assert_eq!(apply_pool_mutations_sorted(root, &[m], &[neighbours]), Err(TransitionError::InvalidSerialization));
+    }
+
+    // ── Signing format vectors ────────────────────────────────────────────────
+
+    #[test]
+    fn genesis_empty_bundle_signing_root_is_pinned() {
+        use crate::state::epoch::{EpochState, DEFAULT_EPOCH_DURATION_SECS};
+
+        let genesis = EpochState::genesis().unwrap();
+        assert_eq!(genesis.epoch_duration_secs, DEFAULT_EPOCH_DURATION_SECS);
+        // Entropy stats and signatures are not hashed; only the mutation vectors
+        // and the (absent) VDF proof are.
+        let bundle = StateWitnessBundle {
+            bond_witnesses:       vec![],
+            entropy_stats:        EntropyStats {
+                active_bonded_magnitude_raw: 500_000_000_000,
+                total_supply_raw:            1_000_000_000_000,
+                unique_active_validators:    5,
+                optimal_validator_count:     10,
+            },
+            impact_witnesses:     vec![],
+            mutation_authors:     vec![],
+            validator_signatures: vec![],
+            validator_witnesses:  vec![],
+            vdf_proof:            None,
+        };
+
+        // PINNED CONSTITUTIONAL VECTOR — DO NOT CHANGE.
+        // SHA-256 of three be4 zero counts and the absent-proof tag (13 zero bytes).
+        let bundle_hash = compute_bundle_hash(&bundle);
+        let expected_bundle_hash: Digest = [
+            0xdd, 0x46, 0xc3, 0xee, 0xbb, 0x18, 0x84, 0xff,
+            0x3b, 0x52, 0x58, 0xc0, 0xa2, 0xfc, 0x93, 0x98,
+            0xe5, 0x60, 0xa2, 0x9e, 0x07, 0x80, 0xd4, 0xb5,
+            0x38, 0x69, 0xb6, 0x25, 0x4a, 0xa4, 0x6a, 0x96,
+        ];
+        assert_eq!(bundle_hash, expected_bundle_hash, "empty bundle hash diverged — bundle format changed");
+
+        // PINNED CONSTITUTIONAL VECTOR — DO NOT CHANGE.
+        // genesis → epoch 1, zero kernel hash, default cadence.
+        let signing_root = compute_epoch_signing_root(
+            &genesis.state_root,
+            &bundle_hash,
+            1,
+            &[0u8; 32],
+            genesis.epoch_duration_secs,
+        );
+        let expected_signing_root: Digest = [
+            0xb6, 0xe1, 0xf3, 0xce, 0xf4, 0xb7, 0x2c, 0x66,
+            0x96, 0x58, 0x68, 0xf6, 0xac, 0xbe, 0xc9, 0x6d,
+            0xad, 0x42, 0x2c, 0xdd, 0x27, 0xe0, 0xc4, 0x49,
+            0xbc, 0x36, 0x77, 0x32, 0x76, 0x91, 0x3b, 0x5b,
+        ];
+        assert_eq!(signing_root, expected_signing_root, "epoch signing root diverged — signing format changed");
+    }
+
+    #[test]
+    fn streamed_bundle_hash_matches_the_frozen_byte_layout() {
+        let m = |k: &[u8], old: &[u8], new: &[u8]| LeafMutation {
+            key: k.to_vec(), old_value: old.to_vec(), new_value: new.to_vec(), path: MerklePath::new(vec![]).unwrap(),
+            append_proof: None,
+        };
+        let mut bundle = StateWitnessBundle {
+            bond_witnesses:       vec![m(b"b1", b"", b"x"), m(b"b2", b"y", b"zz")],
+            entropy_stats:        EntropyStats {
+                active_bonded_magnitude_raw: 0,
+                total_supply_raw:            1,
+                unique_active_validators:    0,
+                optimal_validator_count:     1,
+            },
+            impact_witnesses:     vec![],
+            mutation_authors:     vec![(1, [0xAA; 32])],
+            validator_signatures: vec![],
+            validator_witnesses:  vec![m(b"v", b"", &[7u8; 300])],
+            vdf_proof:            None,
+        };
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&2u32.to_be_bytes());
+        expected.extend_from_slice(b"\x00\x02b1\x00\x00\x00\x01x\x00\x02b2\x00\x01y\x00\x02zz");
+        expected.extend_from_slice(&0u32.to_be_bytes());
+        expected.extend_from_slice(&1u32.to_be_bytes());
+        expected.extend_from_slice(b"\x00\x01v\x00\x00\x01\x2c");
+        expected.extend_from_slice(&[7u8; 300]);
+        expected.push(0x00);
+        expected.extend_from_slice(&1u32.to_be_bytes());
+        expected.extend_from_slice(&1u32.to_be_bytes());
+        expected.extend_from_slice(&[0xAA; 32]);
+        assert_eq!(compute_bundle_hash(&bundle), sha256(&expected));
+
+        bundle.mutation_authors.clear();
+        expected.truncate(expected.len() - 40);
+        assert_eq!(compute_bundle_hash(&bundle), sha256(&expected));
+
+        // A present proof is tagged and length-prefixed, ahead of any authors.
+        bundle.vdf_proof = Some(vec![0xDE, 0xAD]);
+        expected.pop();
+        expected.extend_from_slice(b"\x01\x00\x00\x00\x00\x00\x00\x00\x02\xDE\xAD");
+        assert_eq!(compute_bundle_hash(&bundle), sha256(&expected));
+    }
+
+    // ── Pointer-width independence ────────────────────────────────────────────
+    // These vectors run on every target: a usize-dependent cast breaks one.
+
+    fn mutation(key: &[u8], old: &[u8], new: &[u8]) -> LeafMutation {
+        LeafMutation {
+            key: key.to_vec(),
+            old_value: old.to_vec(),
+            new_value: new.to_vec(),
+            path: MerklePath::new(vec![]).unwrap(),
+            append_proof: None,
+        }
+    }
+
+    fn bundle(validator_witnesses: Vec<LeafMutation>) -> StateWitnessBundle {
+        StateWitnessBundle {
+            bond_witnesses: vec![],
+            entropy_stats: EntropyStats {
+                active_bonded_magnitude_raw: 0,
+                total_supply_raw: 1,
+                unique_active_validators: 0,
+                optimal_validator_count: 1,
+            },
+            impact_witnesses: vec![],
+            mutation_authors: vec![],
+            validator_signatures: vec![],
+            validator_witnesses,
+            vdf_proof: None,
+        }
+    }
+
+    #[test]
+    fn bundle_hash_length_prefixes_are_fixed_width() {
+        // Hand-assemble the frozen format with explicit u32/u16 literals —
+        // never usize — and compare.
+        let b = bundle(vec![mutation(b"k", b"", b"vv")]);
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&0u32.to_be_bytes()); // bond
+        expected.extend_from_slice(&0u32.to_be_bytes()); // impact
+        expected.extend_from_slice(&1u32.to_be_bytes()); // validator
+        expected.extend_from_slice(&1u16.to_be_bytes());
+        expected.extend_from_slice(b"k");
+        expected.extend_from_slice(&0u16.to_be_bytes());
+        expected.extend_from_slice(&2u16.to_be_bytes());
+        expected.extend_from_slice(b"vv");
+        expected.push(0x00); // no VDF proof
+        assert_eq!(compute_bundle_hash(&b), sha256(&expected));
+    }
+
+    #[test]
+    fn bundle_hash_at_max_value_size_is_pinned() {
+        // A MAX_VALUE_BYTES value exercises the widest be2 prefix (0x1000).
+        let big = vec![0xABu8; MAX_VALUE_BYTES];
+        let b = bundle(vec![mutation(b"k", &[], &big)]);
+        b.validate_limits().unwrap();
+        // PINNED on a 64-bit host — a wasm32 run must reproduce it exactly.
+        let expected: Digest = [
+            0x17, 0xc6, 0x92, 0xf4, 0x56, 0x56, 0x32, 0x4b,
+            0x0a, 0xfb, 0x32, 0x66, 0xc1, 0x25, 0xf2, 0xf3,
+            0x34, 0x15, 0xf1, 0x09, 0x9c, 0xe0, 0x33, 0x80,
+            0x6b, 0xc0, 0x1b, 0x79, 0x18, 0x61, 0x8e, 0x6a,
+        ];
+        assert_eq!(compute_bundle_hash(&b), expected);
+    }
+
+    #[test]
+    fn oversized_value_cannot_reach_be2_prefix() {
+        // 65_536 would wrap to 0 in a u16 prefix; validate_limits rejects it first.
+        let b = bundle(vec![mutation(b"k", &[], &vec![0u8; 65_536])]);
+        assert_eq!(b.validate_limits(), Err(TransitionError::InvalidSerialization));
+    }
+
+    // ── ValidatorSignature wire format ────────────────────────────────────────
+
+    fn sign_for_test(signing_root: &Digest, seed: u8) -> ValidatorSignature {
+        use ed25519_dalek::{SigningKey, Signer};
+        let signing_key = SigningKey::from_bytes(&[seed; 32]);
+        ValidatorSignature {
+            validator_pubkey: signing_key.verifying_key().to_bytes(),
+            signature: signing_key.sign(signing_root).to_bytes(),
+        }
+    }
+
+    #[test]
+    fn ed25519_quorum_trait_matches_verify_quorum() {
+        let signing_root = sha256(b"epoch");
+        let mut sigs: Vec<ValidatorSignature> =
+            (1u8..=4).map(|seed| sign_for_test(&signing_root, seed)).collect();
+        sigs.sort_by_key(|s| s.validator_pubkey);
+        let mut forged = sigs.clone();
+        forged[1].signature[0] ^= 1;
+        let mut reversed = sigs.clone();
+        reversed.reverse();
+
+        for (set, optimal) in [(&sigs, 6), (&sigs, 7), (&sigs, 0), (&forged, 4), (&reversed, 4)] {
+            let mut meter = WorkMeter::default();
+            let via_trait = Ed25519Quorum.verify(set, &signing_root, quorum_threshold(optimal), &mut meter);
+            assert_eq!(via_trait, verify_quorum(set, &signing_root, optimal));
+        }
+        // A bad signature rejects only after every signature was checked.
+        let mut meter = WorkMeter::default();
+        assert_eq!(verify_quorum_metered(&forged, &signing_root, 4, &mut meter), Err(TransitionError::InvalidSignature));
+        assert_eq!(meter.signature_verifications, 4);
+        assert_eq!(verify_quorum(&sigs, &signing_root, 7), Err(TransitionError::InvalidSignature));
+        assert_eq!(quorum_threshold(6), 4);
+        assert_eq!(quorum_threshold(7), 5);
+        assert_eq!(quorum_threshold(0), 0);
+    }
+
+    #[test]
+    fn abstentions_are_exactly_the_non_signers() {
+        let signing_root = sha256(b"epoch");
+        let all: Vec<ValidatorSignature> =
+            (1u8..=6).map(|seed| sign_for_test(&signing_root, seed)).collect();
+        let expected: Vec<[u8; 32]> = all.iter().map(|s| s.validator_pubkey).collect();
+
+        // Validators 1, 3, 4 and 6 sign: a 4-of-6 quorum (threshold = 4).
+        let mut signed = vec![all[0].clone(), all[2].clone(), all[3].clone(), all[5].clone()];
+        signed.sort_by_key(|s| s.validator_pubkey);
+        assert_eq!(verify_quorum(&signed, &signing_root, 6), Ok(()));
+
+        let mut non_signers = vec![expected[1], expected[4]];
+        non_signers.sort();
+        assert_eq!(compute_abstentions(&expected, &signed), non_signers);
+        assert!(compute_abstentions(&expected, &all).is_empty());
+        assert_eq!(compute_abstentions(&expected, &[]).len(), 6);
+    }
+
+    #[test]
+    fn validator_signature_bytes_round_trip() {
+        let sig = ValidatorSignature { validator_pubkey: [0xAA; 32], signature: [0xBB; 64] };
+        let bytes = sig.to_bytes();
+        assert_eq!(&bytes[..32], &[0xAA; 32]);
+        assert_eq!(&bytes[32..], &[0xBB; 64]);
+        assert_eq!(ValidatorSignature::from_bytes(&bytes), sig);
+    }
+
+    #[test]
+    fn signature_set_round_trip_preserves_quorum_order() {
+        let signing_root = sha256(b"epoch");
+        let mut sigs: Vec<ValidatorSignature> =
+            (1u8..=3).map(|seed| sign_for_test(&signing_root, seed)).collect();
+        sigs.sort_by_key(|s| s.validator_pubkey);
+
+        let wire = serialize_signatures(&sigs);
+        assert_eq!(wire.len(), 4 + 3 * VALIDATOR_SIGNATURE_BYTES);
+        assert_eq!(&wire[..4], &3u32.to_be_bytes());
+
+        let decoded = deserialize_signatures(&wire).unwrap();
+        assert_eq!(decoded, sigs);
+        verify_quorum(&decoded, &signing_root, 3).unwrap();
+    }
+
+    #[test]
+    fn signature_set_rejects_malformed_lengths() {
+        let wire = serialize_signatures(&[ValidatorSignature { validator_pubkey: [1; 32], signature: [2; 64] }]);
+        assert_eq!(deserialize_signatures(&wire[..3]), Err(TransitionError::InvalidSerialization));
+        assert_eq!(deserialize_signatures(&wire[..wire.len() - 1]), Err(TransitionError::InvalidSerialization));
+        let mut trailing = wire.clone();
+        trailing.push(0);
+        assert_eq!(deserialize_signatures(&trailing), Err(TransitionError::InvalidSerialization));
+        assert_eq!(deserialize_signatures(&serialize_signatures(&[])).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn signature_set_rejects_oversized_count() {
+        let count = (MAX_VALIDATOR_SIGNATURES as u32 + 1).to_be_bytes();
+        assert_eq!(deserialize_signatures(&count), Err(TransitionError::PayloadLimitExceeded));
+    }
+
+    // ── Audit JSON form ───────────────────────────────────────────────────────
+
+    #[test]
+    fn bundle_canonical_json_is_canonical_and_parses_back() {
+        use crate::physics::canonical_json::{canonicalize, parse, Value};
+
+        let bundle = StateWitnessBundle {
+            bond_witnesses: vec![],
+            entropy_stats: EntropyStats {
+                active_bonded_magnitude_raw: 500,
+                total_supply_raw: 1000,
+                unique_active_validators: 2,
+                optimal_validator_count: 3,
+            },
+            impact_witnesses: vec![],
+            mutation_authors: vec![],
+            validator_signatures: vec![ValidatorSignature { validator_pubkey: [0xAB; 32], signature: [0x01; 64] }],
+            validator_witnesses: vec![LeafMutation {
+                key: b"k".to_vec(),
+                old_value: vec![],
+                new_value: vec![0xFF],
+                path: MerklePath::new(vec![MerklePathNode {
+                    sibling: [0x00; 32],
+                    position: NodePosition::Right,
+                }]).unwrap(),
+                append_proof: None,
+            }],
+            vdf_proof: None,
+        };
+        let json = bundle.to_canonical_json().unwrap();
+        assert_eq!(canonicalize(&json).unwrap(), json, "output must already be canonical");
+
+        let v = parse(&json).unwrap();
+        let stats = v.get("entropy_stats").unwrap();
+        assert_eq!(stats.get("total_supply_raw").and_then(Value::as_str_bytes), Some(&b"1000"[..]));
+        let m = &v.get("validator_witnesses").and_then(Value::as_array).unwrap()[0];
+        assert_eq!(m.get("key").and_then(Value::as_str_bytes), Some(&b"6b"[..]));
+        assert_eq!(m.get("new_value").and_then(Value::as_str_bytes), Some(&b"ff"[..]));
+        assert_eq!(m.get("old_value").and_then(Value::as_str_bytes), Some(&b""[..]));
+        let node = &m.get("path").and_then(Value::as_array).unwrap()[0];
+        assert_eq!(node.get("position").and_then(Value::as_str_bytes), Some(&b"right"[..]));
+        let sig = &v.get("validator_signatures").and_then(Value::as_array).unwrap()[0];
+        assert_eq!(sig.get("validator_pubkey").and_then(Value::as_str_bytes).map(|h| h.len()), Some(64));
+        assert_eq!(v.get("vdf_proof"), Some(&Value::Null));
+        assert_eq!(v.get("bond_witnesses"), Some(&Value::Array(vec![])));
+    }
 }
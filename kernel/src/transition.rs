@@ -48,8 +48,9 @@
 
 use crate::TransitionError;
 use crate::physics::hashing::Digest;
-use crate::state::epoch::{EpochState, MAX_PAYLOADS_PER_EPOCH};
-use crate::state::witness::StateWitnessBundle;
+use crate::state::epoch::{EpochState, SupplyDeltas, MAX_PAYLOADS_PER_EPOCH};
+use crate::physics::vdf::{NoVdf, VdfVerifier};
+use crate::state::witness::{Ed25519Quorum, QuorumVerifier, StateWitnessBundle, WorkMeter};
 
 // ──────────────────────────────────────────────────────────────────────────────
 // Public API
@@ -127,7 +128,9 @@ pub fn apply_epoch_dry_run(
     let new_state = EpochState {
         bond_pool_root:        new_bond_pool_root,
         entropy_metric_scaled: new_entropy_metric_scaled,
+        epoch_duration_secs:   prev.epoch_duration_secs,
         epoch_number:          new_epoch_number,
+        governance_root:       prev.governance_root,
         impact_pool_root:      new_impact_pool_root,
         kernel_hash,
         previous_root:         new_previous_root,
@@ -175,15 +178,274 @@ pub fn apply_epoch_dry_run(
 /// - All three Merkle pool roots updated via witness-authenticated mutations
 /// - `entropy_metric_scaled` computed from witness entropy stats
 /// - `state_root` = SHA256(canonical JSON of all other fields)
-/// - `vdf_challenge_seed` = all zeros (stub until v0.1.0)
+/// - `vdf_challenge_seed` = all zeros (stub until v0.1.0). Runs with `NoVdf`,
+///   so a bundle carrying a `vdf_proof` is rejected with `InvalidVdfProof`.
 pub fn apply_epoch(
     prev:        &EpochState,
     witness:     &StateWitnessBundle,
     kernel_hash: Digest,
 ) -> Result<EpochState, TransitionError> {
+    apply_epoch_with_vdf(prev, witness, kernel_hash, &NoVdf)
+}
+
+/// `apply_epoch` with a pluggable VDF verifier.
+///
+/// If `witness.vdf_proof` is present, the new `vdf_challenge_seed` is exactly
+/// `vdf.verify_and_extract_seed(prev.state_root, proof)`; a failing proof aborts
+/// the epoch. With no proof, the seed is all zeros. No other input can reach
+/// the seed.
+pub fn apply_epoch_with_vdf<V: VdfVerifier>(
+    prev:        &EpochState,
+    witness:     &StateWitnessBundle,
+    kernel_hash: Digest,
+    vdf:         &V,
+) -> Result<EpochState, TransitionError> {
+    execute_epoch(prev, witness, kernel_hash, vdf, &NoExtraChecks, &Ed25519Quorum).map(|receipt| receipt.state)
+}
+
+/// `apply_epoch` for a fixed-length chain whose last epoch is `max_epoch`.
+///
+/// Returns `EpochLimitReached` when `prev.epoch_number >= max_epoch`, before
+/// any other check, so a bounded network halts deterministically at its
+/// terminal epoch. `None` is exactly `apply_epoch`.
+pub fn apply_epoch_with_limit(
+    prev:        &EpochState,
+    witness:     &StateWitnessBundle,
+    kernel_hash: Digest,
+    max_epoch:   Option<u64>,
+) -> Result<EpochState, TransitionError> {
+    if let Some(max) = max_epoch {
+        if prev.epoch_number >= max {
+            return Err(TransitionError::EpochLimitReached);
+        }
+    }
+    apply_epoch(prev, witness, kernel_hash)
+}
+
+/// `apply_epoch` with the `prev_state_root` the signers declare they signed over.
+///
+/// Diagnostic only: `compute_epoch_signing_root` already binds `prev.state_root`,
+/// so a bundle signed over a stale base fails the quorum check regardless. When
+/// the host supplies `signed_prev_root`, a mismatch is reported as
+/// `StaleBaseState` before any signature is verified, instead of the generic
+/// `InvalidSignature`. A matching root proves nothing and is not trusted;
+/// `None` is exactly `apply_epoch`.
+pub fn apply_epoch_with_signed_base(
+    prev:             &EpochState,
+    witness:          &StateWitnessBundle,
+    kernel_hash:      Digest,
+    signed_prev_root: Option<Digest>,
+) -> Result<EpochState, TransitionError> {
+    use crate::physics::hashing::ct_eq;
+
+    if let Some(root) = signed_prev_root {
+        if !ct_eq(&root, &prev.state_root) {
+            return Err(TransitionError::StaleBaseState);
+        }
+    }
+    apply_epoch(prev, witness, kernel_hash)
+}
+
+/// Deployment-specific policy run by `apply_epoch_with_checks`.
+///
+/// Runs after the bundle's size and entropy validation and before the
+/// signature gate or any pool mutation. A check can only reject an epoch, never
+/// alter its output, so every deployment still agrees on the state of any
+/// epoch it accepts. Must be deterministic.
+pub trait PreTransitionCheck {
+    /// Return an error to reject the epoch.
+    fn check(&self, prev: &EpochState, witness: &StateWitnessBundle) -> Result<(), TransitionError>;
+}
+
+/// The empty policy: `apply_epoch_with_checks` with it is exactly `apply_epoch`.
+pub struct NoExtraChecks;
+
+impl PreTransitionCheck for NoExtraChecks {
+    fn check(&self, _prev: &EpochState, _witness: &StateWitnessBundle) -> Result<(), TransitionError> {
+        Ok(())
+    }
+}
+
+/// `apply_epoch` with a deployment policy layered on top (see `PreTransitionCheck`).
+pub fn apply_epoch_with_checks<C: PreTransitionCheck>(
+    prev:        &EpochState,
+    witness:     &StateWitnessBundle,
+    kernel_hash: Digest,
+    checks:      &C,
+) -> Result<EpochState, TransitionError> {
+    execute_epoch(prev, witness, kernel_hash, &NoVdf, checks, &Ed25519Quorum).map(|receipt| receipt.state)
+}
+
+/// `apply_epoch` with a pluggable quorum signature scheme (see `QuorumVerifier`).
+///
+/// With `Ed25519Quorum` this is exactly `apply_epoch`.
+pub fn apply_epoch_with_quorum<Q: QuorumVerifier>(
+    prev:        &EpochState,
+    witness:     &StateWitnessBundle,
+    kernel_hash: Digest,
+    quorum:      &Q,
+) -> Result<EpochState, TransitionError> {
+    execute_epoch(prev, witness, kernel_hash, &NoVdf, &NoExtraChecks, quorum).map(|receipt| receipt.state)
+}
+
+/// Verify a received `(prev, witness, claimed_next)` triple.
+///
+/// Re-executes `apply_epoch(prev, witness, kernel_hash)` and compares the
+/// resulting `state_root` with `claimed_next.state_root` in constant time.
+/// Since `state_root` commits to every other field, one comparison covers the
+/// whole state. Any `apply_epoch` error is returned unchanged; a root
+/// divergence returns `StateRootMismatch`.
+pub fn verify_transition(
+    prev:         &EpochState,
+    witness:      &StateWitnessBundle,
+    kernel_hash:  Digest,
+    claimed_next: &EpochState,
+) -> Result<(), TransitionError> {
+    use crate::physics::hashing::ct_eq;
+
+    let next = apply_epoch(prev, witness, kernel_hash)?;
+    if !ct_eq(&next.state_root, &claimed_next.state_root) {
+        return Err(TransitionError::StateRootMismatch);
+    }
+    Ok(())
+}
+
+/// Verify a chain segment backwards from a trusted `tip`, without replaying.
+///
+/// `segment` is ordered oldest-to-newest and must end at `tip`. Walking from
+/// the tip back to `segment[0]`, every state must pass `verify_self` and
+/// chain to its predecessor: `previous_root` equal to the predecessor's
+/// `state_root` and `epoch_number` exactly one higher. This authenticates
+/// history that hashes back to `tip`; it does NOT re-execute any transition.
+///
+/// # Errors
+///
+/// - `InvalidSerialization` — `segment` is empty, or a link is broken.
+/// - `StateRootMismatch` — the last state is not `tip`, or a state's
+///   `state_root` does not match its fields.
+pub fn verify_chain_backwards(tip: &EpochState, segment: &[EpochState]) -> Result<(), TransitionError> {
+    use crate::physics::hashing::ct_eq;
+
+    let last = segment.last().ok_or(TransitionError::InvalidSerialization)?;
+    tip.verify_self()?;
+    if !ct_eq(&last.state_root, &tip.state_root) {
+        return Err(TransitionError::StateRootMismatch);
+    }
+    for i in (0..segment.len()).rev() {
+        segment[i].verify_self()?;
+        if i == 0 {
+            break;
+        }
+        let (parent, child) = (&segment[i - 1], &segment[i]);
+        if !ct_eq(&child.previous_root, &parent.state_root)
+            || parent.epoch_number.checked_add(1) != Some(child.epoch_number)
+        {
+            return Err(TransitionError::InvalidSerialization);
+        }
+    }
+    Ok(())
+}
+
+/// The outcome of `apply_epoch_with_receipt`: the new state plus side-channel
+/// facts about how it was reached. Nothing in the receipt is committed to
+/// `state_root`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionReceipt {
+    /// The committed post-transition state (identical to `apply_epoch`'s output).
+    pub state: EpochState,
+    /// Work performed to produce `state`.
+    pub work: WorkMeter,
+    /// Which pool roots differ from `prev`.
+    pub pools: PoolChanges,
+    /// `true` if all three pool roots and `entropy_metric_scaled` equal `prev`'s:
+    /// only the counter and root chain advanced. Still a valid epoch; hosts may
+    /// rate-limit these at the policy layer.
+    pub is_vacuous: bool,
+    /// The exact bytes `commit()` hashed into `state.state_root`, for byte-diffing
+    /// a disputed root. `Some` only with the `capture-canonical` feature.
+    pub debug_canonical_bytes: Option<Vec<u8>>,
+    /// Intermediate values in execution order, for diffing two nodes that
+    /// disagree on a root. Non-empty only with the `trace` feature.
+    pub trace: Vec<TraceStep>,
+    /// Supply movements during the epoch, for `next_entropy_stats`. Always zero
+    /// from `apply_epoch_with_receipt` in v0.0.2 (no emission or slashing is
+    /// wired in); a host applying either outside the kernel fills it in.
+    pub supply: SupplyDeltas,
+}
+
+/// One recorded intermediate value of an epoch execution (`trace` feature).
+///
+/// A successful epoch records, in order: `EpochNumber`, `PreviousRoot`,
+/// `SigningRoot`, `ValidatorSetRoot`, `ImpactPoolRoot`, `BondPoolRoot`,
+/// `Entropy`, `VdfSeed`, `StateRoot`. The first entry that differs between
+/// two nodes' traces names the step that diverged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceStep {
+    EpochNumber(u64),
+    PreviousRoot(Digest),
+    SigningRoot(Digest),
+    ValidatorSetRoot(Digest),
+    ImpactPoolRoot(Digest),
+    BondPoolRoot(Digest),
+    /// `entropy_metric_scaled` as committed.
+    Entropy(u128),
+    VdfSeed(Digest),
+    StateRoot(Digest),
+}
+
+/// `apply_epoch`, additionally returning the execution trace.
+///
+/// DEBUGGING ONLY (`trace` feature). The state is bit-identical to
+/// `apply_epoch`; see `TraceStep` for the recorded sequence.
+#[cfg(feature = "trace")]
+pub fn apply_epoch_traced(
+    prev:        &EpochState,
+    witness:     &StateWitnessBundle,
+    kernel_hash: Digest,
+) -> Result<(EpochState, Vec<TraceStep>), TransitionError> {
+    let receipt = apply_epoch_with_receipt(prev, witness, kernel_hash)?;
+    Ok((receipt.state, receipt.trace))
+}
+
+/// Per-pool flags: `true` if the pool root differs from the previous epoch's.
+///
+/// An empty witness vector passes the previous root through unchanged, so a
+/// host that expected a pool to change can detect an omitted witness set here.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolChanges {
+    pub validator_set: bool,
+    pub impact_pool:   bool,
+    pub bond_pool:     bool,
+}
+
+/// `apply_epoch`, additionally returning a `TransitionReceipt`.
+///
+/// The state is bit-identical to `apply_epoch` for the same inputs; the
+/// receipt only observes the execution.
+pub fn apply_epoch_with_receipt(
+    prev:        &EpochState,
+    witness:     &StateWitnessBundle,
+    kernel_hash: Digest,
+) -> Result<TransitionReceipt, TransitionError> {
+    execute_epoch(prev, witness, kernel_hash, &NoVdf, &NoExtraChecks, &Ed25519Quorum)
+}
+
+/// The single v0.0.2 execution path behind every `apply_epoch*` entry point.
+fn execute_epoch<V: VdfVerifier, C: PreTransitionCheck, Q: QuorumVerifier>(
+    prev:        &EpochState,
+    witness:     &StateWitnessBundle,
+    kernel_hash: Digest,
+    vdf:         &V,
+    checks:      &C,
+    quorum:      &Q,
+) -> Result<TransitionReceipt, TransitionError> {
     use crate::math::fixed::Fixed;
     use crate::state::entropy::compute_entropy;
-    use crate::state::witness::apply_pool_mutations;
+    use crate::state::witness::{apply_pool_mutations_metered, reject_deletions};
+
+    let mut work = WorkMeter::default();
+    #[cfg(feature = "trace")]
+    let mut trace = Vec::new();
 
     // ── Step 1: Validate bundle size limits ───────────────────────────────────
     // Reject oversized bundles before touching any Merkle state.
@@ -194,6 +456,10 @@ pub fn apply_epoch(
     // A failed entropy check aborts the epoch with no partial state mutation.
     witness.entropy_stats.validate()?;
 
+    // ── Step 2b: Deployment policy ────────────────────────────────────────────
+    // Host-layered rules run on the validated bundle, before any state work.
+    checks.check(prev, witness)?;
+
     // ── Step 3: Epoch number (checked increment) ──────────────────────────────
     let new_epoch_number = prev
         .epoch_number
@@ -202,6 +468,8 @@ pub fn apply_epoch(
 
     // ── Step 4: Chain the previous state root ─────────────────────────────────
     let new_previous_root = prev.state_root;
+    #[cfg(feature = "trace")]
+    trace.extend([TraceStep::EpochNumber(new_epoch_number), TraceStep::PreviousRoot(new_previous_root)]);
 
     // ── Step 5: Signature gate ────────────────────────────────────────────────
     // Authorization boundary: verify that a quorum of validators has signed
@@ -210,7 +478,10 @@ pub fn apply_epoch(
     // HOST-TRUSTED (v0.0.2): Signature pubkeys are not verified against
     // validator_set_root. Full Merkle membership proofs required in v0.0.3.
     {
-        use crate::state::witness::{compute_bundle_hash, compute_epoch_signing_root, verify_quorum};
+        use crate::state::witness::{
+            compute_bundle_hash, compute_epoch_signing_root, quorum_threshold, verify_mutation_authors,
+            verify_validator_keys,
+        };
 
         let bundle_hash = compute_bundle_hash(witness);
         let signing_root = compute_epoch_signing_root(
@@ -218,34 +489,55 @@ pub fn apply_epoch(
             &bundle_hash,
             new_epoch_number,
             &kernel_hash,
+            prev.epoch_duration_secs,
         );
-        verify_quorum(
+        work.sha256_invocations += 2;
+        #[cfg(feature = "trace")]
+        trace.push(TraceStep::SigningRoot(signing_root));
+        quorum.verify(
             &witness.validator_signatures,
             &signing_root,
-            witness.entropy_stats.optimal_validator_count,
+            quorum_threshold(witness.entropy_stats.optimal_validator_count),
+            &mut work,
         )?;
+        // Per-mutation attribution, if any, must name verified signers.
+        verify_mutation_authors(witness)?;
+        // Validator-pool keys are pubkeys, and registrations are self-authored.
+        verify_validator_keys(witness)?;
     }
 
     // ── Step 6: Validator pool (registration + decay pass) ────────────────────
     // validator_witnesses covers both registration and decay mutations.
     // Within the array, registration mutations come first (lower keys),
     // decay mutations after; lexicographic order is enforced by apply_pool_mutations.
-    let new_validator_set_root = apply_pool_mutations(
+    let new_validator_set_root = apply_pool_mutations_metered(
         prev.validator_set_root,
         &witness.validator_witnesses,
+        &mut work,
     )?;
+    #[cfg(feature = "trace")]
+    trace.push(TraceStep::ValidatorSetRoot(new_validator_set_root));
 
     // ── Step 7: Impact pool ───────────────────────────────────────────────────
-    let new_impact_pool_root = apply_pool_mutations(
+    // Impact and bond leaves are immutable records: no tombstones (DELETE).
+    reject_deletions(&witness.impact_witnesses)?;
+    let new_impact_pool_root = apply_pool_mutations_metered(
         prev.impact_pool_root,
         &witness.impact_witnesses,
+        &mut work,
     )?;
+    #[cfg(feature = "trace")]
+    trace.push(TraceStep::ImpactPoolRoot(new_impact_pool_root));
 
     // ── Step 8: Bond pool ─────────────────────────────────────────────────────
-    let new_bond_pool_root = apply_pool_mutations(
+    reject_deletions(&witness.bond_witnesses)?;
+    let new_bond_pool_root = apply_pool_mutations_metered(
         prev.bond_pool_root,
         &witness.bond_witnesses,
+        &mut work,
     )?;
+    #[cfg(feature = "trace")]
+    trace.push(TraceStep::BondPoolRoot(new_bond_pool_root));
 
     // ── Step 9: Entropy computation ───────────────────────────────────────────
     // Convert raw u128 fields to Fixed and delegate to compute_entropy().
@@ -260,10 +552,18 @@ pub fn apply_epoch(
         witness.entropy_stats.optimal_validator_count,
     )?;
     let new_entropy_metric_scaled = entropy.raw();
+    #[cfg(feature = "trace")]
+    trace.push(TraceStep::Entropy(new_entropy_metric_scaled));
 
     // ── Step 9: VDF challenge seed ────────────────────────────────────────────
-    // STUB: Real seed is un-biasable VDF output (deferred to v0.1.0).
-    let new_vdf_challenge_seed: Digest = [0u8; 32];
+    // Derived ONLY from the verifier's output, challenged with the previous
+    // state root. No proof → all-zero seed (VDF not yet mandatory, v0.1.0).
+    let new_vdf_challenge_seed: Digest = match &witness.vdf_proof {
+        Some(proof) => vdf.verify_and_extract_seed(&prev.state_root, proof)?,
+        None        => [0u8; 32],
+    };
+    #[cfg(feature = "trace")]
+    trace.push(TraceStep::VdfSeed(new_vdf_challenge_seed));
 
     // ── Step 10: Assemble and commit ──────────────────────────────────────────
     // commit() = canonicalize() → sha256() → assign state_root → Ok(self).
@@ -271,7 +571,9 @@ pub fn apply_epoch(
     let new_state = EpochState {
         bond_pool_root:        new_bond_pool_root,
         entropy_metric_scaled: new_entropy_metric_scaled,
+        epoch_duration_secs:   prev.epoch_duration_secs,
         epoch_number:          new_epoch_number,
+        governance_root:       prev.governance_root,
         impact_pool_root:      new_impact_pool_root,
         kernel_hash,
         previous_root:         new_previous_root,
@@ -280,7 +582,38 @@ pub fn apply_epoch(
         vdf_challenge_seed:    new_vdf_challenge_seed,
     };
 
-    new_state.commit()
+    let pools = PoolChanges {
+        validator_set: new_validator_set_root != prev.validator_set_root,
+        impact_pool:   new_impact_pool_root != prev.impact_pool_root,
+        bond_pool:     new_bond_pool_root != prev.bond_pool_root,
+    };
+
+    let is_vacuous = pools == PoolChanges::default()
+        && new_state.entropy_metric_scaled == prev.entropy_metric_scaled;
+
+    let state = new_state.commit()?;
+    work.sha256_invocations += 1;
+
+    // Observational only: re-serializes the committed state, never re-hashes it.
+    #[cfg(feature = "capture-canonical")]
+    let debug_canonical_bytes = Some(state.canonical_bytes()?);
+    #[cfg(not(feature = "capture-canonical"))]
+    let debug_canonical_bytes = None;
+
+    #[cfg(feature = "trace")]
+    trace.push(TraceStep::StateRoot(state.state_root));
+    #[cfg(not(feature = "trace"))]
+    let trace = Vec::new();
+
+    Ok(TransitionReceipt {
+        state,
+        work,
+        pools,
+        is_vacuous,
+        debug_canonical_bytes,
+        trace,
+        supply: SupplyDeltas::default(),
+    })
 }
 
 // ──────────────────────────────────────────────────────────────────────────────
@@ -292,7 +625,7 @@ mod tests {
     use crate::state::epoch::EpochState;
 
     fn zero_genesis() -> EpochState {
-        EpochState::genesis()
+        EpochState::genesis().unwrap()
     }
 
     // ── Basic correctness ─────────────────────────────────────────────────────
@@ -437,10 +770,10 @@ mod tests {
         // Any change to apply_epoch_dry_run, EpochState serialization, sha256,
         // or canonical_json will break this assertion and signal a chain fork.
         let expected: [u8; 32] = [
-            0x10, 0xdc, 0x6e, 0x69, 0x48, 0x43, 0xa9, 0xa3,
-            0x81, 0x3f, 0xec, 0xb4, 0x91, 0x99, 0xf5, 0xf8,
-            0x1a, 0xb6, 0x1d, 0xa2, 0x0f, 0xe5, 0x36, 0xa0,
-            0x9d, 0xb3, 0xb1, 0xfb, 0xf1, 0x90, 0x8e, 0xa1,
+            0xc6, 0xbb, 0x87, 0xeb, 0x07, 0x1c, 0x77, 0xb3,
+            0xd5, 0xb3, 0x8d, 0x09, 0x34, 0xd2, 0x7f, 0x91,
+            0xee, 0x54, 0x3a, 0x0c, 0x45, 0x46, 0xae, 0xb5,
+            0xbf, 0xc4, 0x98, 0x45, 0x9e, 0x8e, 0x1f, 0xb7,
         ];
         assert_eq!(e1.state_root, expected,
             "epoch 1 state_root diverged — execution path changed");
@@ -505,10 +838,10 @@ mod tests {
         //   payload_count = 0, kernel_hash = [0u8; 32] at every epoch.
         // Any execution drift surfaces within 100 epochs.
         let expected: [u8; 32] = [
-            0x23, 0x86, 0x15, 0xdb, 0x67, 0x8a, 0xcd, 0x7b,
-            0xe8, 0x46, 0x0b, 0x8d, 0xd2, 0x50, 0x15, 0xf9,
-            0x56, 0x06, 0x70, 0xa1, 0xac, 0x17, 0xd0, 0x83,
-            0x6f, 0xae, 0x6a, 0x42, 0x72, 0xb3, 0x57, 0x99,
+            0xdc, 0x71, 0x64, 0x2c, 0x29, 0x3c, 0xf1, 0x92,
+            0x4f, 0x7a, 0x7c, 0x7b, 0xc6, 0x3e, 0x57, 0x2c,
+            0x99, 0x1a, 0xb0, 0xd1, 0x1b, 0x11, 0x82, 0x9a,
+            0xbd, 0x3b, 0x44, 0x0e, 0x34, 0x9e, 0x8e, 0x30,
         ];
         assert_eq!(state.state_root, expected, "epoch 100 chain diverged — execution drift detected");
     }
@@ -549,9 +882,15 @@ mod tests {
             old_value: old_raw.to_vec(),
             new_value: new_raw.to_vec(),
             path: MerklePath::new(vec![MerklePathNode { sibling, position }]).unwrap(),
+            append_proof: None,
         }
     }
 
+    /// A validator-pool key: the pubkey `[seed; 32]` as 64 lowercase hex characters.
+    fn vkey(seed: u8) -> Vec<u8> {
+        format!("{:02x}", seed).repeat(32).into_bytes()
+    }
+
     fn sign_for_test(signing_root: &Digest, seed: u8) -> crate::state::witness::ValidatorSignature {
         use ed25519_dalek::{SigningKey, Signer};
         let secret_bytes = [seed; 32];
@@ -566,9 +905,10 @@ mod tests {
     fn add_valid_signatures(witness: &mut StateWitnessBundle, prev_root: &Digest, new_epoch_number: u64, kernel_hash: &Digest) {
         let bundle_hash = crate::state::witness::compute_bundle_hash(witness);
         let signing_root = crate::state::witness::compute_epoch_signing_root(
-            prev_root, &bundle_hash, new_epoch_number, kernel_hash
+            prev_root, &bundle_hash, new_epoch_number, kernel_hash,
+            crate::state::epoch::DEFAULT_EPOCH_DURATION_SECS,
         );
-        let threshold = (2 * witness.entropy_stats.optimal_validator_count as usize + 2) / 3;
+        let threshold = (2 * witness.entropy_stats.optimal_validator_count as usize).div_ceil(3);
         let mut sigs = vec![];
         for i in 0..threshold {
             sigs.push(sign_for_test(&signing_root, (i + 1) as u8));
@@ -588,8 +928,10 @@ mod tests {
             bond_witnesses:     vec![],
             entropy_stats:      test_entropy(),
             impact_witnesses:   vec![],
+            mutation_authors:   vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof:           None,
         };
 
         add_valid_signatures(&mut witness, &genesis.state_root, 1, &[0u8; 32]);
@@ -618,28 +960,31 @@ mod tests {
         let initial_validator_root = hash_node(&leaf_v1, &leaf_v2);
         let initial_impact_root    = leaf_i1; // single-leaf: root IS the hash
 
-        let mut initial_state = zero_genesis();
-        initial_state.validator_set_root = initial_validator_root;
-        initial_state.impact_pool_root   = initial_impact_root;
-        // Re-commit to get correct state_root.
-        let initial_state = initial_state.commit().unwrap();
+        let genesis = zero_genesis();
+        let bond_root = genesis.bond_pool_root;
+        let initial_state = genesis
+            .with_roots(initial_validator_root, initial_impact_root, bond_root)
+            .unwrap();
 
         // Validator mutation: v1 → v1_updated (v1 is LEFT child)
-        let v_mutation = epoch_mutation(b"v1", b"v1", b"v1_updated", leaf_v2, NodePosition::Left);
+        let v_mutation = epoch_mutation(&vkey(1), b"v1", b"v1_updated", leaf_v2, NodePosition::Left);
         // Impact mutation: i1 → i1_updated (single leaf, empty path)
         let i_mutation = LeafMutation {
             key: b"i1".to_vec(),
             old_value: b"i1".to_vec(),
             new_value: b"i1_updated".to_vec(),
             path: MerklePath::new(vec![]).unwrap(),
+            append_proof: None,
         };
 
         let mut witness = StateWitnessBundle {
             bond_witnesses:      vec![],
             entropy_stats:       test_entropy(),
             impact_witnesses:    vec![i_mutation],
+            mutation_authors:    vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![v_mutation],
+            vdf_proof:           None,
         };
 
         add_valid_signatures(&mut witness, &initial_state.state_root, 1, &[0u8; 32]);
@@ -672,10 +1017,10 @@ mod tests {
         // Any change to apply_epoch, apply_pool_mutations, compute_entropy,
         // or EpochState serialization will break this assertion immediately.
         let expected_state_root: [u8; 32] = [
-            0x20, 0xba, 0x16, 0x51, 0x8a, 0xac, 0xb1, 0x90,
-            0xc4, 0x6c, 0x10, 0xd0, 0xcf, 0xbd, 0x19, 0xd1,
-            0xf5, 0xe4, 0x6e, 0x4d, 0xf0, 0x81, 0x7b, 0xfd,
-            0x18, 0x5d, 0xd9, 0xc6, 0x2c, 0xeb, 0x2b, 0x0b,
+            0xab, 0x32, 0xdd, 0xd4, 0xd7, 0x30, 0x7b, 0x01,
+            0x72, 0x51, 0x9a, 0x51, 0x7b, 0x8c, 0xca, 0xad,
+            0x05, 0x8f, 0xe4, 0xd9, 0x8c, 0x43, 0xc2, 0xa7,
+            0x8f, 0x6c, 0xb8, 0x55, 0xc7, 0x16, 0x9c, 0x5c,
         ];
         assert_eq!(next.state_root, expected_state_root,
             "multi-pool epoch state_root diverged — apply_epoch execution path changed");
@@ -694,7 +1039,7 @@ mod tests {
 
         // Wrong sibling → path will not verify.
         let bad_mutation = epoch_mutation(
-            b"v1", b"v1", b"v1_updated",
+            &vkey(1), b"v1", b"v1_updated",
             hash_leaf(b"WRONG_SIBLING"), // corrupted
             NodePosition::Left,
         );
@@ -703,8 +1048,10 @@ mod tests {
             bond_witnesses:      vec![],
             entropy_stats:       test_entropy(),
             impact_witnesses:    vec![],
+            mutation_authors:    vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![bad_mutation],
+            vdf_proof:           None,
         };
 
         add_valid_signatures(&mut witness, &state.state_root, 1, &[0u8; 32]);
@@ -729,13 +1076,15 @@ mod tests {
                 optimal_validator_count:     10,
             },
             impact_witnesses:    vec![],
+            mutation_authors:    vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof:           None,
         };
 
         assert_eq!(
             apply_epoch(&zero_genesis(), &witness, [0u8; 32]),
-            Err(TransitionError::MathOverflow),
+            Err(TransitionError::MathUnderflow),
             "bonded > supply must fail before any pool mutation"
         );
     }
@@ -755,8 +1104,10 @@ mod tests {
                 optimal_validator_count: 3, // threshold = (2*3+2)/3 = 2
             },
             impact_witnesses: vec![],
+            mutation_authors: vec![],
             validator_signatures: vec![], // will populate
             validator_witnesses: vec![],
+            vdf_proof: None,
         };
 
         let bundle_hash = crate::state::witness::compute_bundle_hash(&witness);
@@ -765,6 +1116,7 @@ mod tests {
             &bundle_hash,
             1, // new_epoch_number
             &[0u8; 32], // kernel_hash
+            prev_state.epoch_duration_secs,
         );
 
         let sig1 = sign_for_test(&signing_root, 1);
@@ -793,8 +1145,10 @@ mod tests {
                 optimal_validator_count: 4, // threshold = (2*4+2)/3 = 3
             },
             impact_witnesses: vec![],
+            mutation_authors: vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof: None,
         };
 
         let bundle_hash = crate::state::witness::compute_bundle_hash(&witness);
@@ -803,6 +1157,7 @@ mod tests {
             &bundle_hash,
             1,
             &[0u8; 32],
+            prev_state.epoch_duration_secs,
         );
 
         // Only 2 signatures for a threshold of 3
@@ -828,13 +1183,15 @@ mod tests {
             bond_witnesses: vec![],
             entropy_stats: test_entropy(),
             impact_witnesses: vec![],
+            mutation_authors: vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof: None,
         };
 
         let bundle_hash = crate::state::witness::compute_bundle_hash(&witness);
         let signing_root = crate::state::witness::compute_epoch_signing_root(
-            &prev_state.state_root, &bundle_hash, 1, &[0u8; 32]
+            &prev_state.state_root, &bundle_hash, 1, &[0u8; 32], prev_state.epoch_duration_secs
         );
 
         let sig = sign_for_test(&signing_root, 1);
@@ -854,13 +1211,15 @@ mod tests {
             bond_witnesses: vec![],
             entropy_stats: test_entropy(),
             impact_witnesses: vec![],
+            mutation_authors: vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof: None,
         };
 
         let bundle_hash = crate::state::witness::compute_bundle_hash(&witness);
         let signing_root = crate::state::witness::compute_epoch_signing_root(
-            &prev_state.state_root, &bundle_hash, 1, &[0u8; 32]
+            &prev_state.state_root, &bundle_hash, 1, &[0u8; 32], prev_state.epoch_duration_secs
         );
 
         let sig1 = sign_for_test(&signing_root, 1);
@@ -885,14 +1244,16 @@ mod tests {
             bond_witnesses: vec![],
             entropy_stats: test_entropy(),
             impact_witnesses: vec![],
+            mutation_authors: vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof: None,
         };
 
         let bundle_hash = crate::state::witness::compute_bundle_hash(&witness);
         // Signed with kernel hash [0; 32]
         let signing_root = crate::state::witness::compute_epoch_signing_root(
-            &prev_state.state_root, &bundle_hash, 1, &[0u8; 32]
+            &prev_state.state_root, &bundle_hash, 1, &[0u8; 32], prev_state.epoch_duration_secs
         );
 
         let sig = sign_for_test(&signing_root, 1);
@@ -918,14 +1279,16 @@ mod tests {
             bond_witnesses: vec![],
             entropy_stats: test_entropy(),
             impact_witnesses: vec![],
+            mutation_authors: vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof: None,
         };
 
         let bundle_hash = crate::state::witness::compute_bundle_hash(&witness);
         // Signed for epoch 7 (wrong!)
         let signing_root = crate::state::witness::compute_epoch_signing_root(
-            &prev_state.state_root, &bundle_hash, 7, &[0u8; 32]
+            &prev_state.state_root, &bundle_hash, 7, &[0u8; 32], prev_state.epoch_duration_secs
         );
 
         let sig = sign_for_test(&signing_root, 1);
@@ -946,13 +1309,15 @@ mod tests {
             bond_witnesses: vec![],
             entropy_stats: test_entropy(),
             impact_witnesses: vec![],
+            mutation_authors: vec![],
             validator_signatures: vec![],
             validator_witnesses: vec![],
+            vdf_proof: None,
         };
 
         let bundle_hash = crate::state::witness::compute_bundle_hash(&witness);
         let signing_root = crate::state::witness::compute_epoch_signing_root(
-            &prev_state.state_root, &bundle_hash, 1, &[0u8; 32]
+            &prev_state.state_root, &bundle_hash, 1, &[0u8; 32], prev_state.epoch_duration_secs
         );
 
         // Sign the EMPTY bundle
@@ -968,7 +1333,8 @@ mod tests {
             path: MerklePath::new(vec![MerklePathNode {
                 sibling: [0u8; 32],
                 position: NodePosition::Left,
-            }]).unwrap()
+            }]).unwrap(),
+            append_proof: None,
         });
 
         assert_eq!(
@@ -977,4 +1343,692 @@ mod tests {
             "signature must fail if bundle content changes after signing"
         );
     }
+
+    // ── Bundle canonicality ───────────────────────────────────────────────────
+
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    enum Pool { Bond, Impact, Validator }
+
+    fn bundle_from_pools(bond: Vec<LeafMutation>, impact: Vec<LeafMutation>, validator: Vec<LeafMutation>) -> StateWitnessBundle {
+        StateWitnessBundle {
+            bond_witnesses:       bond,
+            entropy_stats:        test_entropy(),
+            impact_witnesses:     impact,
+            mutation_authors:     vec![],
+            validator_signatures: vec![],
+            validator_witnesses:  validator,
+            vdf_proof:            None,
+        }
+    }
+
+    #[test]
+    fn independent_hosts_build_identical_bundle_hashes() {
+        use crate::state::witness::compute_bundle_hash;
+
+        // A logical mutation set. A mutation's pool is a property of its payload
+        // type, carried with it, never inferred from the key.
+        let logical: Vec<(Pool, LeafMutation)> = [
+            (Pool::Validator, b"v3".as_slice()), (Pool::Bond, b"b2"), (Pool::Impact, b"i1"),
+            (Pool::Validator, b"v1"), (Pool::Impact, b"i9"), (Pool::Bond, b"b1"),
+            (Pool::Validator, b"v2"), (Pool::Impact, b"i5"),
+        ]
+        .iter()
+        .map(|&(pool, key)| (pool, epoch_mutation(key, key, &[key, b"'"].concat(), [0u8; 32], NodePosition::Left)))
+        .collect();
+
+        // Host A: receives in arrival order, buckets by pool, then sorts each bucket.
+        let host_a = {
+            let (mut bond, mut impact, mut validator) = (vec![], vec![], vec![]);
+            for (pool, m) in logical.iter().cloned() {
+                match pool {
+                    Pool::Bond      => bond.push(m),
+                    Pool::Impact    => impact.push(m),
+                    Pool::Validator => validator.push(m),
+                }
+            }
+            for bucket in [&mut bond, &mut impact, &mut validator] {
+                bucket.sort_by(|x, y| x.key.cmp(&y.key));
+            }
+            bundle_from_pools(bond, impact, validator)
+        };
+
+        // Host B: receives in reverse order, sorts globally by (pool, key), then splits.
+        let host_b = {
+            let mut all: Vec<(Pool, LeafMutation)> = logical.iter().rev().cloned().collect();
+            all.sort_by(|x, y| (x.0, &x.1.key).cmp(&(y.0, &y.1.key)));
+            let take = |p: Pool| all.iter().filter(|(q, _)| *q == p).map(|(_, m)| m.clone()).collect();
+            bundle_from_pools(take(Pool::Bond), take(Pool::Impact), take(Pool::Validator))
+        };
+
+        assert_eq!(compute_bundle_hash(&host_a), compute_bundle_hash(&host_b));
+        for bundle in [&host_a, &host_b] {
+            for pool in [&bundle.bond_witnesses, &bundle.impact_witnesses, &bundle.validator_witnesses] {
+                assert!(pool.windows(2).all(|w| w[0].key < w[1].key));
+            }
+        }
+
+        // The hash is order-sensitive, which is why the sorted order is mandatory.
+        let mut unsorted = host_a.clone();
+        unsorted.validator_witnesses.swap(0, 1);
+        assert_ne!(compute_bundle_hash(&unsorted), compute_bundle_hash(&host_a));
+    }
+
+    // ── WorkMeter ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn receipt_work_meter_matches_hand_count() {
+        // Validator pool [v1, v2] → [v1', v2'] via two depth-1 mutations
+        // (Model A: v2's sibling is the already-updated v1'). Two signers.
+        let leaf_v1 = hash_leaf(b"v1");
+        let leaf_v2 = hash_leaf(b"v2");
+        let mut prev = zero_genesis();
+        prev.validator_set_root = hash_node(&leaf_v1, &leaf_v2);
+        let prev = prev.commit().unwrap();
+
+        let mut witness = StateWitnessBundle {
+            bond_witnesses: vec![],
+            entropy_stats: EntropyStats {
+                active_bonded_magnitude_raw: 0,
+                total_supply_raw: 1000,
+                unique_active_validators: 1,
+                optimal_validator_count: 3, // threshold = 2
+            },
+            impact_witnesses: vec![],
+            mutation_authors: vec![],
+            validator_signatures: vec![],
+            validator_witnesses: vec![
+                epoch_mutation(&vkey(1), b"v1", b"v1'", leaf_v2, NodePosition::Left),
+                epoch_mutation(&vkey(2), b"v2", b"v2'", hash_leaf(b"v1'"), NodePosition::Right),
+            ],
+            vdf_proof: None,
+        };
+        add_valid_signatures(&mut witness, &prev.state_root, 1, &[0u8; 32]);
+        assert_eq!(witness.validator_signatures.len(), 2);
+
+        let receipt = apply_epoch_with_receipt(&prev, &witness, [0u8; 32]).unwrap();
+
+        // SHA-256: bundle hash (1) + signing root (1)
+        //        + per mutation: old leaf + new leaf + verify walk + rebuild walk (4) × 2
+        //        + state root (1) = 11
+        assert_eq!(receipt.work, WorkMeter {
+            sha256_invocations:      11,
+            signature_verifications: 2,
+            merkle_steps:            4,
+        });
+        assert_eq!(receipt.state, apply_epoch(&prev, &witness, [0u8; 32]).unwrap(),
+            "metering must not change the resulting state");
+        assert_eq!(receipt.supply, SupplyDeltas::default(), "v0.0.2 neither mints nor slashes");
+    }
+
+    #[test]
+    fn receipt_canonical_bytes_rehash_to_state_root() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        let receipt = apply_epoch_with_receipt(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(receipt.state, apply_epoch(&prev, &witness, [0u8; 32]).unwrap());
+
+        #[cfg(feature = "capture-canonical")]
+        {
+            let bytes = receipt.debug_canonical_bytes.expect("captured under capture-canonical");
+            assert_eq!(crate::physics::hashing::sha256(&bytes), receipt.state.state_root);
+            assert_eq!(bytes, receipt.state.canonical_bytes().unwrap());
+        }
+        #[cfg(not(feature = "capture-canonical"))]
+        assert_eq!(receipt.debug_canonical_bytes, None);
+        #[cfg(not(feature = "trace"))]
+        assert!(receipt.trace.is_empty());
+    }
+
+    #[test]
+    fn receipt_flags_pool_left_unchanged_by_omitted_witnesses() {
+        // Impact pool [i1, i2] and validator pool [v1, v2]; the host sends the
+        // validator update but omits the impact witnesses.
+        let leaf_v1 = hash_leaf(b"v1");
+        let leaf_v2 = hash_leaf(b"v2");
+        let mut prev = zero_genesis();
+        prev.validator_set_root = hash_node(&leaf_v1, &leaf_v2);
+        prev.impact_pool_root   = hash_node(&hash_leaf(b"i1"), &hash_leaf(b"i2"));
+        let prev = prev.commit().unwrap();
+
+        let mut witness = StateWitnessBundle {
+            bond_witnesses:      vec![],
+            entropy_stats:       test_entropy(),
+            impact_witnesses:    vec![],
+            mutation_authors:    vec![],
+            validator_signatures: vec![],
+            validator_witnesses: vec![
+                epoch_mutation(&vkey(1), b"v1", b"v1'", leaf_v2, NodePosition::Left),
+            ],
+            vdf_proof:           None,
+        };
+        add_valid_signatures(&mut witness, &prev.state_root, 1, &[0u8; 32]);
+
+        let receipt = apply_epoch_with_receipt(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(receipt.state.impact_pool_root, prev.impact_pool_root);
+        assert_eq!(receipt.pools, PoolChanges {
+            validator_set: true,
+            impact_pool:   false,
+            bond_pool:     false,
+        });
+    }
+
+    #[test]
+    fn receipt_flags_vacuous_epoch() {
+        // The first empty epoch moves entropy off genesis's 0; the second changes nothing.
+        let g = zero_genesis();
+        let first = apply_epoch_with_receipt(&g, &signed_empty_bundle(&g, None), [0u8; 32]).unwrap();
+        assert_ne!(first.state.entropy_metric_scaled, g.entropy_metric_scaled);
+        assert!(!first.is_vacuous);
+
+        let mut empty = signed_empty_bundle(&g, None);
+        empty.validator_signatures.clear();
+        add_valid_signatures(&mut empty, &first.state.state_root, 2, &[0u8; 32]);
+        let second = apply_epoch_with_receipt(&first.state, &empty, [0u8; 32]).unwrap();
+        assert!(second.is_vacuous);
+        assert_eq!(second.state.epoch_number, 2);
+
+        // A mutating epoch with unchanged entropy is not vacuous.
+        let leaf_v1 = hash_leaf(b"v1");
+        let leaf_v2 = hash_leaf(b"v2");
+        let prev = first.state.with_roots(hash_node(&leaf_v1, &leaf_v2), [0u8; 32], [0u8; 32]).unwrap();
+        let mut witness = StateWitnessBundle {
+            bond_witnesses:       vec![],
+            entropy_stats:        test_entropy(),
+            impact_witnesses:     vec![],
+            mutation_authors:     vec![],
+            validator_signatures: vec![],
+            validator_witnesses:  vec![epoch_mutation(&vkey(1), b"v1", b"v1'", leaf_v2, NodePosition::Left)],
+            vdf_proof:            None,
+        };
+        add_valid_signatures(&mut witness, &prev.state_root, 2, &[0u8; 32]);
+        let receipt = apply_epoch_with_receipt(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(receipt.state.entropy_metric_scaled, prev.entropy_metric_scaled);
+        assert!(!receipt.is_vacuous);
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn trace_records_every_step_and_ends_at_the_committed_root() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        let (state, trace) = apply_epoch_traced(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(state, apply_epoch(&prev, &witness, [0u8; 32]).unwrap());
+        assert_eq!(trace.len(), 9);
+        assert_eq!(trace[0], TraceStep::EpochNumber(1));
+        assert_eq!(trace[1], TraceStep::PreviousRoot(prev.state_root));
+        assert_eq!(trace[8], TraceStep::StateRoot(state.state_root));
+    }
+
+    // ── VDF seed derivation ───────────────────────────────────────────────────
+
+    /// Test verifier: accepts any proof, outputs SHA256(challenge || proof).
+    struct HashVdf;
+
+    impl VdfVerifier for HashVdf {
+        fn verify_and_extract_seed(&self, challenge: &Digest, proof: &[u8]) -> Result<Digest, TransitionError> {
+            let mut buf = challenge.to_vec();
+            buf.extend_from_slice(proof);
+            Ok(crate::physics::hashing::sha256(&buf))
+        }
+    }
+
+    /// Test verifier: ignores the proof bytes entirely and outputs a constant.
+    struct ConstVdf([u8; 32]);
+
+    impl VdfVerifier for ConstVdf {
+        fn verify_and_extract_seed(&self, _challenge: &Digest, _proof: &[u8]) -> Result<Digest, TransitionError> {
+            Ok(self.0)
+        }
+    }
+
+    fn signed_empty_bundle(prev: &EpochState, vdf_proof: Option<Vec<u8>>) -> StateWitnessBundle {
+        let mut witness = StateWitnessBundle {
+            bond_witnesses:      vec![],
+            entropy_stats:       test_entropy(),
+            impact_witnesses:    vec![],
+            mutation_authors:    vec![],
+            validator_signatures: vec![],
+            validator_witnesses: vec![],
+            vdf_proof,
+        };
+        add_valid_signatures(&mut witness, &prev.state_root, 1, &[0u8; 32]);
+        witness
+    }
+
+    #[test]
+    fn vdf_no_proof_keeps_zero_seed() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        let next = apply_epoch_with_vdf(&prev, &witness, [0u8; 32], &NoVdf).unwrap();
+        assert_eq!(next.vdf_challenge_seed, [0u8; 32]);
+        assert_eq!(next, apply_epoch(&prev, &witness, [0u8; 32]).unwrap());
+    }
+
+    #[test]
+    fn vdf_seed_is_verifier_output() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, Some(b"proof".to_vec()));
+        let next = apply_epoch_with_vdf(&prev, &witness, [0u8; 32], &HashVdf).unwrap();
+        let expected = HashVdf.verify_and_extract_seed(&prev.state_root, b"proof").unwrap();
+        assert_eq!(next.vdf_challenge_seed, expected);
+    }
+
+    #[test]
+    fn vdf_proof_bytes_cannot_become_the_seed() {
+        // A host sets the "proof" to the seed it wants. The seed must still be
+        // whatever the verifier outputs, not the supplied bytes.
+        let prev = zero_genesis();
+        let wanted = [0x66u8; 32];
+        let witness = signed_empty_bundle(&prev, Some(wanted.to_vec()));
+        let next = apply_epoch_with_vdf(&prev, &witness, [0u8; 32], &ConstVdf([0x11; 32])).unwrap();
+        assert_eq!(next.vdf_challenge_seed, [0x11; 32]);
+        assert_ne!(next.vdf_challenge_seed, wanted);
+    }
+
+    #[test]
+    fn vdf_proof_is_bound_by_the_signatures() {
+        // A relayer swapping the proof under valid signatures would choose the
+        // next seed; the proof is in the bundle hash, so the quorum breaks.
+        let prev = zero_genesis();
+        let mut witness = signed_empty_bundle(&prev, Some(b"proof".to_vec()));
+        assert!(apply_epoch_with_vdf(&prev, &witness, [0u8; 32], &HashVdf).is_ok());
+        witness.vdf_proof = Some(b"other".to_vec());
+        assert_eq!(
+            apply_epoch_with_vdf(&prev, &witness, [0u8; 32], &HashVdf),
+            Err(TransitionError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn vdf_proof_without_verifier_is_rejected() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, Some(b"proof".to_vec()));
+        assert_eq!(apply_epoch(&prev, &witness, [0u8; 32]), Err(TransitionError::InvalidVdfProof));
+    }
+
+    // ── Quiescent network ─────────────────────────────────────────────────────
+
+    #[test]
+    fn apply_epoch_commits_zero_entropy_for_inactive_epoch() {
+        let prev = zero_genesis();
+        let mut witness = StateWitnessBundle {
+            bond_witnesses:      vec![],
+            entropy_stats:       EntropyStats {
+                active_bonded_magnitude_raw: 0,
+                total_supply_raw:            1_000_000_000_000_u128,
+                unique_active_validators:    0,
+                optimal_validator_count:     10,
+            },
+            impact_witnesses:    vec![],
+            mutation_authors:    vec![],
+            validator_signatures: vec![],
+            validator_witnesses: vec![],
+            vdf_proof:           None,
+        };
+        add_valid_signatures(&mut witness, &prev.state_root, 1, &[0u8; 32]);
+
+        let next = apply_epoch(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(next.entropy_metric_scaled, 0);
+        assert_eq!(next.epoch_number, 1);
+    }
+
+    // ── DELETE (tombstone) handling ───────────────────────────────────────────
+
+    /// Two-leaf pool [x, y] with a signed witness deleting `x` in the chosen pool.
+    fn delete_epoch(pool: &str) -> (EpochState, StateWitnessBundle) {
+        let root = hash_node(&hash_leaf(b"x"), &hash_leaf(b"y"));
+        let mut prev = zero_genesis();
+        match pool {
+            "validator" => prev.validator_set_root = root,
+            "impact"    => prev.impact_pool_root = root,
+            _           => prev.bond_pool_root = root,
+        }
+        let prev = prev.commit().unwrap();
+        let delete = vec![epoch_mutation(&vkey(0), b"x", b"", hash_leaf(b"y"), NodePosition::Left)];
+        let mut witness = StateWitnessBundle {
+            bond_witnesses:      if pool == "bond" { delete.clone() } else { vec![] },
+            entropy_stats:       test_entropy(),
+            impact_witnesses:    if pool == "impact" { delete.clone() } else { vec![] },
+            mutation_authors:    vec![],
+            validator_signatures: vec![],
+            validator_witnesses: if pool == "validator" { delete } else { vec![] },
+            vdf_proof:           None,
+        };
+        add_valid_signatures(&mut witness, &prev.state_root, 1, &[0u8; 32]);
+        (prev, witness)
+    }
+
+    #[test]
+    fn delete_in_impact_pool_is_rejected() {
+        let (prev, witness) = delete_epoch("impact");
+        assert_eq!(apply_epoch(&prev, &witness, [0u8; 32]), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn delete_in_bond_pool_is_rejected() {
+        let (prev, witness) = delete_epoch("bond");
+        assert_eq!(apply_epoch(&prev, &witness, [0u8; 32]), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn delete_in_validator_pool_writes_tombstone() {
+        // Documented semantics: the slot becomes hash_leaf([]); the tree keeps
+        // its shape and is NOT equal to the one-leaf tree [y].
+        let (prev, witness) = delete_epoch("validator");
+        let next = apply_epoch(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(next.validator_set_root, hash_node(&hash_leaf(b""), &hash_leaf(b"y")));
+        assert_ne!(next.validator_set_root, hash_leaf(b"y"));
+    }
+
+    // ── verify_transition ─────────────────────────────────────────────────────
+
+    #[test]
+    fn verify_transition_accepts_correct_triple() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        let next = apply_epoch(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(verify_transition(&prev, &witness, [0u8; 32], &next), Ok(()));
+    }
+
+    #[test]
+    fn verify_transition_rejects_tampered_root() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        let mut claimed = apply_epoch(&prev, &witness, [0u8; 32]).unwrap();
+        claimed.state_root[31] ^= 0x01;
+        assert_eq!(
+            verify_transition(&prev, &witness, [0u8; 32], &claimed),
+            Err(TransitionError::StateRootMismatch)
+        );
+    }
+
+    // ── verify_chain_backwards ────────────────────────────────────────────────
+
+    /// Genesis followed by `len - 1` dry-run epochs, oldest first.
+    fn dry_run_chain(len: usize) -> Vec<EpochState> {
+        let mut chain = vec![zero_genesis()];
+        while chain.len() < len {
+            let next = apply_epoch_dry_run(chain.last().unwrap(), 0, [0u8; 32]).unwrap();
+            chain.push(next);
+        }
+        chain
+    }
+
+    #[test]
+    fn backward_segment_from_trusted_tip_verifies() {
+        let chain = dry_run_chain(6);
+        let tip = chain[5].clone();
+        assert_eq!(verify_chain_backwards(&tip, &chain), Ok(()));
+        // Any suffix ending at the tip is a valid segment, down to the tip alone.
+        assert_eq!(verify_chain_backwards(&tip, &chain[3..]), Ok(()));
+        assert_eq!(verify_chain_backwards(&tip, &chain[5..]), Ok(()));
+        assert_eq!(verify_chain_backwards(&tip, &[]), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn backward_segment_with_broken_link_is_rejected() {
+        let chain = dry_run_chain(6);
+        let tip = chain[5].clone();
+
+        // A re-committed state 2 with a different field: self-consistent, but
+        // state 3 no longer chains to it.
+        let mut forked = chain.clone();
+        forked[2].entropy_metric_scaled = 1;
+        forked[2] = forked[2].clone().commit().unwrap();
+        assert_eq!(verify_chain_backwards(&tip, &forked), Err(TransitionError::InvalidSerialization));
+
+        // A mid-segment field edit without re-committing fails verify_self.
+        let mut tampered = chain.clone();
+        tampered[3].entropy_metric_scaled = 1;
+        assert_eq!(verify_chain_backwards(&tip, &tampered), Err(TransitionError::StateRootMismatch));
+
+        // A skipped epoch breaks the link even though the roots are untouched.
+        let skipped = [chain[2].clone(), chain[4].clone(), chain[5].clone()];
+        assert_eq!(verify_chain_backwards(&tip, &skipped), Err(TransitionError::InvalidSerialization));
+    }
+
+    #[test]
+    fn backward_segment_not_ending_at_tip_is_rejected() {
+        let chain = dry_run_chain(6);
+        assert_eq!(verify_chain_backwards(&chain[5], &chain[..5]), Err(TransitionError::StateRootMismatch));
+
+        // A tip whose root does not match its own fields is not trusted either.
+        let mut tip = chain[5].clone();
+        tip.epoch_number += 1;
+        assert_eq!(verify_chain_backwards(&tip, &chain), Err(TransitionError::StateRootMismatch));
+    }
+
+    // ── Epoch ceiling ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn epoch_limit_accepts_transition_below_ceiling() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        let next = apply_epoch_with_limit(&prev, &witness, [0u8; 32], Some(1)).unwrap();
+        assert_eq!(next.epoch_number, 1);
+        assert_eq!(next, apply_epoch_with_limit(&prev, &witness, [0u8; 32], None).unwrap());
+        assert_eq!(next, apply_epoch(&prev, &witness, [0u8; 32]).unwrap());
+    }
+
+    #[test]
+    fn epoch_limit_rejects_transition_at_ceiling() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        assert_eq!(
+            apply_epoch_with_limit(&prev, &witness, [0u8; 32], Some(0)),
+            Err(TransitionError::EpochLimitReached)
+        );
+
+        let mut at_ceiling = prev.clone();
+        at_ceiling.epoch_number = 7;
+        let at_ceiling = at_ceiling.commit().unwrap();
+        assert_eq!(
+            apply_epoch_with_limit(&at_ceiling, &witness, [0u8; 32], Some(7)),
+            Err(TransitionError::EpochLimitReached)
+        );
+    }
+
+    #[test]
+    fn signed_base_reports_stale_parent_before_crypto() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        let next = apply_epoch(&prev, &witness, [0u8; 32]).unwrap();
+
+        // Replaying the bundle on top of `next`: the signatures cover `prev`.
+        assert_eq!(apply_epoch(&next, &witness, [0u8; 32]), Err(TransitionError::InvalidSignature));
+        assert_eq!(
+            apply_epoch_with_signed_base(&next, &witness, [0u8; 32], Some(prev.state_root)),
+            Err(TransitionError::StaleBaseState)
+        );
+
+        // A correct declaration, or none, is exactly apply_epoch.
+        assert_eq!(apply_epoch_with_signed_base(&prev, &witness, [0u8; 32], Some(prev.state_root)), Ok(next.clone()));
+        assert_eq!(apply_epoch_with_signed_base(&prev, &witness, [0u8; 32], None), Ok(next.clone()));
+        // Declaring the matching root does not bypass the signature gate.
+        assert_eq!(
+            apply_epoch_with_signed_base(&next, &witness, [0u8; 32], Some(next.state_root)),
+            Err(TransitionError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn epoch_duration_is_carried_forward_and_signature_bound() {
+        use crate::state::epoch::{epoch_signing_message, DEFAULT_EPOCH_DURATION_SECS};
+
+        let hourly = EpochState { epoch_duration_secs: 3_600, ..zero_genesis() }.commit().unwrap();
+
+        // Signatures made over the default cadence do not authorize an hourly chain.
+        let stale = signed_empty_bundle(&hourly, None);
+        assert_eq!(apply_epoch(&hourly, &stale, [0u8; 32]), Err(TransitionError::InvalidSignature));
+
+        let mut witness = stale.clone();
+        let message = epoch_signing_message(&hourly, &witness, &[0u8; 32]).unwrap();
+        witness.validator_signatures = (1..=7).map(|seed| sign_for_test(&message, seed)).collect();
+        witness.validator_signatures.sort_by_key(|s| s.validator_pubkey);
+        let next = apply_epoch(&hourly, &witness, [0u8; 32]).unwrap();
+        assert_eq!(next.epoch_duration_secs, 3_600);
+        assert_ne!(next.epoch_duration_secs, DEFAULT_EPOCH_DURATION_SECS);
+    }
+
+    /// Caps validator INSERTs (registrations) per epoch.
+    struct RegistrationCap(usize);
+
+    impl PreTransitionCheck for RegistrationCap {
+        fn check(&self, _prev: &EpochState, witness: &StateWitnessBundle) -> Result<(), TransitionError> {
+            let registrations = witness.validator_witnesses.iter().filter(|m| m.old_value.is_empty()).count();
+            if registrations > self.0 {
+                return Err(TransitionError::PayloadLimitExceeded);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn pre_transition_check_can_reject_and_no_extra_checks_passes_through() {
+        let insert = |key: Vec<u8>, value: &[u8], path: MerklePath| LeafMutation {
+            key, old_value: vec![], new_value: value.to_vec(), path, append_proof: None,
+        };
+        // Register two validators into an empty pool: slot form, then frontier form.
+        let mut witness = StateWitnessBundle {
+            bond_witnesses:      vec![],
+            entropy_stats:       test_entropy(),
+            impact_witnesses:    vec![],
+            mutation_authors:    vec![],
+            validator_signatures: vec![],
+            validator_witnesses: vec![
+                insert(vkey(1), b"v1", MerklePath::new(vec![]).unwrap()),
+                insert(vkey(2), b"v2", MerklePath::new(vec![MerklePathNode {
+                    sibling: hash_leaf(b"v1"),
+                    position: NodePosition::Right,
+                }]).unwrap()),
+            ],
+            vdf_proof: None,
+        };
+        let prev = zero_genesis().with_roots(hash_leaf(&[]), hash_leaf(&[]), [0u8; 32]).unwrap();
+        add_valid_signatures(&mut witness, &prev.state_root, 1, &[0u8; 32]);
+
+        assert_eq!(
+            apply_epoch_with_checks(&prev, &witness, [0u8; 32], &RegistrationCap(1)),
+            Err(TransitionError::PayloadLimitExceeded)
+        );
+        let capped = apply_epoch_with_checks(&prev, &witness, [0u8; 32], &RegistrationCap(2)).unwrap();
+        let plain = apply_epoch(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(capped, plain);
+        assert_eq!(apply_epoch_with_checks(&prev, &witness, [0u8; 32], &NoExtraChecks).unwrap(), plain);
+        assert_eq!(plain.validator_set_root, hash_node(&hash_leaf(b"v1"), &hash_leaf(b"v2")));
+    }
+
+    /// Counts signers without checking any signature; stands in for an aggregate scheme.
+    struct CountOnly;
+
+    impl QuorumVerifier for CountOnly {
+        fn verify(
+            &self,
+            signatures: &[crate::state::witness::ValidatorSignature],
+            _:          &Digest,
+            threshold:  u64,
+            _:          &mut WorkMeter,
+        ) -> Result<(), TransitionError> {
+            if (signatures.len() as u64) < threshold {
+                return Err(TransitionError::InvalidSignature);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn quorum_verifier_is_pluggable_and_ed25519_is_the_default() {
+        let prev = zero_genesis();
+        let witness = signed_empty_bundle(&prev, None);
+        let plain = apply_epoch(&prev, &witness, [0u8; 32]).unwrap();
+        assert_eq!(apply_epoch_with_quorum(&prev, &witness, [0u8; 32], &Ed25519Quorum), Ok(plain.clone()));
+
+        // A corrupted signature: Ed25519 rejects it, the substitute scheme does not look.
+        let mut forged = witness.clone();
+        forged.validator_signatures[0].signature[0] ^= 1;
+        assert_eq!(apply_epoch(&prev, &forged, [0u8; 32]), Err(TransitionError::InvalidSignature));
+        assert_eq!(apply_epoch_with_quorum(&prev, &forged, [0u8; 32], &CountOnly), Ok(plain));
+
+        // The threshold is still the kernel's: too few signers fail either way.
+        let mut short = witness.clone();
+        short.validator_signatures.clear();
+        assert_eq!(apply_epoch_with_quorum(&prev, &short, [0u8; 32], &CountOnly), Err(TransitionError::InvalidSignature));
+    }
+
+    #[test]
+    fn mutation_author_outside_quorum_is_rejected() {
+        let leaf_v1 = hash_leaf(b"v1");
+        let leaf_v2 = hash_leaf(b"v2");
+        let prev = zero_genesis().with_roots(hash_node(&leaf_v1, &leaf_v2), [0u8; 32], [0u8; 32]).unwrap();
+        let witness = |authors: Vec<(usize, [u8; 32])>| {
+            let mut w = StateWitnessBundle {
+                bond_witnesses:       vec![],
+                entropy_stats:        test_entropy(),
+                impact_witnesses:     vec![],
+                mutation_authors:     authors,
+                validator_signatures: vec![],
+                validator_witnesses:  vec![epoch_mutation(&vkey(1), b"v1", b"v1_updated", leaf_v2, NodePosition::Left)],
+                vdf_proof:            None,
+            };
+            add_valid_signatures(&mut w, &prev.state_root, 1, &[0u8; 32]);
+            w
+        };
+
+        // No attribution: collective authorization, as before.
+        let collective = apply_epoch(&prev, &witness(vec![]), [0u8; 32]).unwrap();
+
+        // Attributed to a quorum signer: same resulting state.
+        let signer = witness(vec![]).validator_signatures[0].validator_pubkey;
+        assert_eq!(apply_epoch(&prev, &witness(vec![(0, signer)]), [0u8; 32]), Ok(collective));
+
+        // Attributed to a key that did not sign (the signatures still cover the
+        // bundle, attribution included): rejected.
+        assert_eq!(
+            apply_epoch(&prev, &witness(vec![(0, [0xEE; 32])]), [0u8; 32]),
+            Err(TransitionError::InvalidSignature)
+        );
+        // An index past the last mutation is malformed.
+        assert_eq!(
+            apply_epoch(&prev, &witness(vec![(1, signer)]), [0u8; 32]),
+            Err(TransitionError::InvalidSerialization)
+        );
+    }
+
+    #[test]
+    fn validator_key_must_be_the_registering_signer() {
+        // Signer seeds 1 and 2 are both in the quorum; seed 1 registers.
+        let pk1 = sign_for_test(&[0u8; 32], 1).validator_pubkey;
+        let pk2 = sign_for_test(&[0u8; 32], 2).validator_pubkey;
+        let hex = |pk: [u8; 32]| pk.iter().map(|b| format!("{:02x}", b)).collect::<String>().into_bytes();
+        let prev = zero_genesis().with_roots(hash_leaf(&[]), [0u8; 32], [0u8; 32]).unwrap();
+        let witness = |key: Vec<u8>, author: [u8; 32]| {
+            let mut w = StateWitnessBundle {
+                bond_witnesses:       vec![],
+                entropy_stats:        test_entropy(),
+                impact_witnesses:     vec![],
+                mutation_authors:     vec![(0, author)],
+                validator_signatures: vec![],
+                validator_witnesses:  vec![LeafMutation {
+                    key, old_value: vec![], new_value: b"v1".to_vec(), path: MerklePath::new(vec![]).unwrap(),
+                    append_proof: None,
+                }],
+                vdf_proof:            None,
+            };
+            add_valid_signatures(&mut w, &prev.state_root, 1, &[0u8; 32]);
+            w
+        };
+
+        let next = apply_epoch(&prev, &witness(hex(pk1), pk1), [0u8; 32]).unwrap();
+        assert_eq!(next.validator_set_root, hash_leaf(b"v1"));
+
+        // A quorum signer registering someone else's key: the sets disagree.
+        assert_eq!(
+            apply_epoch(&prev, &witness(hex(pk2), pk1), [0u8; 32]),
+            Err(TransitionError::InvalidSignature)
+        );
+        // The same pubkey in another encoding is not a validator key.
+        let upper = String::from_utf8(hex(pk1)).unwrap().to_uppercase().into_bytes();
+        assert_eq!(apply_epoch(&prev, &witness(upper, pk1), [0u8; 32]), Err(TransitionError::InvalidSerialization));
+        assert_eq!(apply_epoch(&prev, &witness(pk1.to_vec(), pk1), [0u8; 32]), Err(TransitionError::InvalidSerialization));
+    }
 }